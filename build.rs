@@ -9,13 +9,15 @@ fn main() {
     println!("cargo:rustc-link-arg=--no-dynamic-linker");
     println!("cargo:rustc-link-arg=-n");
     
-    // Disable position independent code
-    println!("cargo:rustc-link-arg=--no-pie");
-    
-    // Compile assembly startup code
+    // Static-PIE: placed anywhere by the kernel, relocated by `_start` itself
+    println!("cargo:rustc-link-arg=-pie");
+    println!("cargo:rustc-link-arg=-z");
+    println!("cargo:rustc-link-arg=text");
+
+    // Compile assembly startup code and the lazy binding trampoline
     cc::Build::new()
         .file("src/start.s")
-        .flag("-fno-pic")
-        .flag("-fno-pie")
+        .file("src/trampoline.s")
+        .flag("-fpie")
         .compile("start");
 }
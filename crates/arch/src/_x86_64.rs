@@ -1,4 +1,11 @@
 #![allow(unused)]
 mod callable;
+mod hwcaps;
+mod transfer;
 
 pub use callable::*;
+pub use hwcaps::*;
+pub use transfer::*;
+
+/// Platform string the kernel announces through `AT_PLATFORM`
+pub const PLATFORM: &core::ffi::CStr = c"x86_64";
@@ -0,0 +1,77 @@
+use core::arch::x86_64::{__cpuid_count, CpuidResult};
+
+/// `glibc-hwcaps` subdirectories of the x86-64 micro-architecture levels, best first
+pub const HWCAPS: [&str; 3] = ["x86-64-v4", "x86-64-v3", "x86-64-v2"];
+
+fn cpuid(leaf: u32) -> CpuidResult {
+    #[allow(unused_unsafe)]
+    unsafe {
+        __cpuid_count(leaf, 0)
+    }
+}
+
+/// Whether every bit of `mask` is set in `value`
+fn has(value: u32, mask: u32) -> bool {
+    value & mask == mask
+}
+
+/// State components the operating system saves on context switches
+fn xcr0() -> u64 {
+    let (low, high): (u32, u32);
+    unsafe {
+        core::arch::asm!("xgetbv", in("ecx") 0, out("eax") low, out("edx") high, options(nomem, nostack))
+    };
+    ((high as u64) << 32) | low as u64
+}
+
+/// Highest x86-64 micro-architecture level usable here, 1 being the baseline
+pub fn level() -> usize {
+    let basic = cpuid(1);
+    let extended = if cpuid(0x8000_0000).eax >= 0x8000_0001 {
+        cpuid(0x8000_0001).ecx
+    } else {
+        0
+    };
+    let structured = if cpuid(0).eax >= 7 { cpuid(7).ebx } else { 0 };
+
+    // CMPXCHG16B, LAHF/SAHF, POPCNT, SSE3, SSE4.1, SSE4.2 and SSSE3
+    let v2 = has(
+        basic.ecx,
+        1 << 13 | 1 << 23 | 1 | 1 << 19 | 1 << 20 | 1 << 9,
+    ) && has(extended, 1);
+    if !v2 {
+        return 1;
+    }
+
+    // AVX needs the operating system to save the SSE and AVX state
+    let osxsave = has(basic.ecx, 1 << 27);
+    let avx_state = osxsave && has(xcr0() as u32, 0b110);
+
+    // AVX, AVX2, BMI1, BMI2, F16C, FMA, LZCNT and MOVBE
+    let v3 = avx_state
+        && has(basic.ecx, 1 << 28 | 1 << 29 | 1 << 12 | 1 << 22)
+        && has(structured, 1 << 5 | 1 << 3 | 1 << 8)
+        && has(extended, 1 << 5);
+    if !v3 {
+        return 2;
+    }
+
+    // AVX512F, AVX512BW, AVX512CD, AVX512DQ and AVX512VL, with the opmask and ZMM state
+    let v4 = has(xcr0() as u32, 0b1110_0110)
+        && has(structured, 1 << 16 | 1 << 30 | 1 << 28 | 1 << 17 | 1 << 31);
+
+    if v4 { 4 } else { 3 }
+}
+
+/// `glibc-hwcaps` subdirectories whose libraries run here, best first
+pub fn hwcaps() -> &'static [&'static str] {
+    &HWCAPS[HWCAPS.len() + 1 - level().max(1)..]
+}
+
+/// Bytes `XSAVE` needs for the state components the operating system enabled, none
+/// when only `FXSAVE` is available
+pub fn xsave_size() -> Option<usize> {
+    let osxsave = cpuid(0).eax >= 0xd && has(cpuid(1).ecx, 1 << 27);
+
+    osxsave.then(|| cpuid(0xd).ebx as usize)
+}
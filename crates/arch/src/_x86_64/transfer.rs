@@ -0,0 +1,22 @@
+/// Hand the process over to a program entry point, as the kernel does on `execve`.
+///
+/// The stack pointer is set to the argument count of an initial process stack, `rdx`
+/// holds no termination function and the frame pointer is cleared.
+///
+/// # Safety
+///
+/// `entry` must be the entry point of a mapped program and `stack_pointer` a 16-byte
+/// aligned initial stack built for it.
+pub unsafe fn transfer(entry: usize, stack_pointer: usize) -> ! {
+    unsafe {
+        core::arch::asm!(
+            "mov rsp, rdi",
+            "xor ebp, ebp",
+            "xor edx, edx",
+            "jmp rsi",
+            in("rdi") stack_pointer,
+            in("rsi") entry,
+            options(noreturn)
+        )
+    }
+}
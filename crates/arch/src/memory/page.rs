@@ -10,7 +10,18 @@ pub const SIZE: dtype::Addr = 0x1000;
 pub const ALIGNMENT: dtype::Addr = SIZE - 1;
 pub const MASK: dtype::Addr = !ALIGNMENT;
 
-pub const DYNAMIC_OFFSET: dtype::Addr = 0x400000;
+/// Lowest base of a position-independent program, as the kernel's `ELF_ET_DYN_BASE`
+pub const DYNAMIC_BASE: dtype::Addr = 0x5555_5555_4000;
+/// Bits of the page number randomised above `DYNAMIC_BASE`, as `mmap_rnd_bits` defaults to
+pub const DYNAMIC_RANDOM_BITS: u32 = 28;
+
+/// Base of the program interpreter when the layout is deterministic, well above any program
+pub const INTERPRETER_BASE: dtype::Addr = 0x7f00_0000_0000;
+
+/// Base of a position-independent program, `random` picking one of the pages above `DYNAMIC_BASE`
+pub fn dynamic_base(random: u64) -> dtype::Addr {
+    DYNAMIC_BASE + (random & ((1 << DYNAMIC_RANDOM_BITS) - 1)) * SIZE
+}
 
 pub fn round_address_to_lower_page_boundary(address: dtype::Addr) -> dtype::Addr {
     address & MASK
@@ -23,3 +34,8 @@ pub fn align_to_lower_page(address: dtype::Addr) -> dtype::Addr {
 pub fn truncate_to_page(address: dtype::Addr) -> dtype::Addr {
     address & ALIGNMENT.not()
 }
+
+/// First page boundary at or after `address`
+pub fn ceil(address: dtype::Addr) -> dtype::Addr {
+    (address + ALIGNMENT) & MASK
+}
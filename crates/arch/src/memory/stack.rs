@@ -1,8 +1,12 @@
 pub mod atype;
 pub mod auxv;
+pub mod builder;
 
 pub use atype::*;
 pub use auxv::*;
+pub use builder::Builder;
+
+use core::ffi::CStr;
 
 use human::info;
 
@@ -79,6 +83,24 @@ impl Stack {
         core::str::from_utf8(s).ok()
     }
     
+    /// Argument strings, in order
+    pub fn arguments(&self) -> impl Iterator<Item = &'static CStr> + Clone + use<> {
+        let argv = self.argv;
+        (0..self.argc).map(move |index| unsafe { CStr::from_ptr(*argv.add(index) as *const _) })
+    }
+
+    /// Environment strings, in order
+    pub fn environment(&self) -> impl Iterator<Item = &'static CStr> + Clone + use<> {
+        let envp = self.envp;
+        (0..self.envc).map(move |index| unsafe { CStr::from_ptr(*envp.add(index) as *const _) })
+    }
+
+    /// Auxiliary vector entries, without the terminating `AT_NULL`
+    pub fn auxiliary(&self) -> impl Iterator<Item = AuxEntry> + Clone + use<> {
+        let auxv = self.auxv;
+        (0..self.auxc).map(move |index| unsafe { *auxv.add(index) })
+    }
+
     /// Print all command line arguments
     pub fn print_args(&self) {
         info!("Arguments {{");
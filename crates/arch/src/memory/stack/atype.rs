@@ -1,20 +1,25 @@
 pub enum Type {
-    Null = 0,    /* End of vector */
-    Ignore = 1,  /* Entry should be ignored */
-    ExecFD = 2,  /* File descriptor of program */
-    PHdr = 3,    /* Program headers for program */
-    PHEnt = 4,   /* Size of program header entry */
-    PHNum = 5,   /* Number of program headers */
-    PageSz = 6,  /* System page size */
-    Base = 7,    /* Base address of interpreter */
-    Flags = 8,   /* Flags */
-    Entry = 9,   /* Entry point of program */
-    NotELF = 10, /* Program is not ELF */
-    UID = 11,    /* Real uid */
-    EUID = 12,   /* Effective uid */
-    GID = 13,    /* Real gid */
-    EGID = 14,   /* Effective gid */
-    ClkTck = 17, /* Frequency of times() */
+    Null = 0,      /* End of vector */
+    Ignore = 1,    /* Entry should be ignored */
+    ExecFD = 2,    /* File descriptor of program */
+    PHdr = 3,      /* Program headers for program */
+    PHEnt = 4,     /* Size of program header entry */
+    PHNum = 5,     /* Number of program headers */
+    PageSz = 6,    /* System page size */
+    Base = 7,      /* Base address of interpreter */
+    Flags = 8,     /* Flags */
+    Entry = 9,     /* Entry point of program */
+    NotELF = 10,   /* Program is not ELF */
+    UID = 11,      /* Real uid */
+    EUID = 12,     /* Effective uid */
+    GID = 13,      /* Real gid */
+    EGID = 14,     /* Effective gid */
+    Platform = 15, /* String identifying platform */
+    HwCap = 16,    /* Machine-dependent processor capabilities */
+    ClkTck = 17,   /* Frequency of times() */
+    Secure = 23,   /* Secure mode boolean */
+    Random = 25,   /* Address of 16 random bytes */
+    HwCap2 = 26,   /* More machine-dependent processor capabilities */
     ExecFn = 31,
     SysInfo = 32,
     SysInfoEhdr = 33,
@@ -39,7 +44,12 @@ impl Type {
             12 => Self::EUID,
             13 => Self::GID,
             14 => Self::EGID,
+            15 => Self::Platform,
+            16 => Self::HwCap,
             17 => Self::ClkTck,
+            23 => Self::Secure,
+            25 => Self::Random,
+            26 => Self::HwCap2,
             31 => Self::ExecFn,
             32 => Self::SysInfo,
             33 => Self::SysInfoEhdr,
@@ -68,7 +78,12 @@ impl Type {
             Self::EUID => "EUID",
             Self::GID => "GID",
             Self::EGID => "EGID",
+            Self::Platform => "Platform",
+            Self::HwCap => "HwCap",
             Self::ClkTck => "ClkTck",
+            Self::Secure => "Secure",
+            Self::Random => "Random",
+            Self::HwCap2 => "HwCap2",
             Self::ExecFn => "ExecFn",
             Self::SysInfo => "SysInfo",
             Self::SysInfoEhdr => "SysInfoEhdr",
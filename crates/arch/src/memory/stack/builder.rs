@@ -0,0 +1,149 @@
+use core::ffi::CStr;
+
+use super::{AuxEntry, Type};
+
+/// Alignment of the stack pointer at process entry, as the System V ABI requires
+pub const ALIGNMENT: usize = 16;
+
+/// Bytes of `AT_RANDOM` data placed on the stack
+pub const RANDOM_SIZE: usize = 16;
+
+/// Lays out an initial process stack, the inverse of `Stack::from_pointer`.
+///
+/// From the top of the region down come the argument and environment strings, the
+/// platform string, the random bytes and, 16-byte aligned, the argument count followed by
+/// the argument, environment and auxiliary vectors. `AT_RANDOM` and `AT_PLATFORM` point at
+/// the copies made here, added when the given entries lack them, and `AT_EXECFN` at the
+/// copied first argument.
+#[derive(Debug, Clone)]
+pub struct Builder<'a, A, E, X> {
+    arguments: A,
+    environment: E,
+    auxiliary: X,
+    random: [u8; RANDOM_SIZE],
+    platform: &'a CStr,
+}
+
+impl<'a, A, E, X> Builder<'a, A, E, X>
+where
+    A: Iterator<Item = &'a CStr> + Clone,
+    E: Iterator<Item = &'a CStr> + Clone,
+    X: Iterator<Item = AuxEntry> + Clone,
+{
+    pub fn new(arguments: A, environment: E, auxiliary: X, random: [u8; RANDOM_SIZE]) -> Self {
+        Self {
+            arguments,
+            environment,
+            auxiliary,
+            random,
+            platform: crate::PLATFORM,
+        }
+    }
+
+    /// Announce another platform than the one xelf runs on
+    pub fn platform(mut self, platform: &'a CStr) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Auxiliary entries as written, with the ones pointing into the stack appended when missing
+    fn entries(&self) -> impl Iterator<Item = AuxEntry> + Clone + use<'a, A, E, X> {
+        let missing = |atype: Type| {
+            let atype = atype.to();
+            let present = self.auxiliary.clone().any(|entry| entry.atype == atype);
+            (!present).then_some(AuxEntry { atype, value: 0 })
+        };
+
+        self.auxiliary
+            .clone()
+            .filter(|entry| entry.atype != Type::Null.to())
+            .chain(missing(Type::Random))
+            .chain(missing(Type::Platform))
+    }
+
+    /// Bytes taken by argument and environment strings with their terminators
+    fn strings_size(&self) -> usize {
+        self.arguments
+            .clone()
+            .chain(self.environment.clone())
+            .map(|string| string.to_bytes_with_nul().len())
+            .sum()
+    }
+
+    /// Words taken by the argument count and the three vectors with their terminators
+    fn vectors_size(&self) -> usize {
+        1 + self.arguments.clone().count()
+            + 1
+            + self.environment.clone().count()
+            + 1
+            + 2 * (self.entries().count() + 1)
+    }
+
+    /// Bytes the stack needs at most, alignment included
+    pub fn size(&self) -> usize {
+        self.strings_size()
+            + self.platform.to_bytes_with_nul().len()
+            + RANDOM_SIZE
+            + self.vectors_size() * size_of::<usize>()
+            + ALIGNMENT
+    }
+
+    /// Lay the stack out at the top of `region` and return the stack pointer, or `None`
+    /// when the region is smaller than `size`
+    pub fn write(&self, region: &mut [u8]) -> Option<*mut u64> {
+        if region.len() < self.size() {
+            return None;
+        }
+
+        let base = region.as_mut_ptr() as usize;
+        let strings = base + region.len() - self.strings_size();
+        let platform = strings - self.platform.to_bytes_with_nul().len();
+        let random = platform - RANDOM_SIZE;
+        let pointer = (random - self.vectors_size() * size_of::<usize>()) & !(ALIGNMENT - 1);
+
+        // Copy `bytes` to `address` and return the address right after them
+        let copy = |address: usize, bytes: &[u8]| unsafe {
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), address as *mut u8, bytes.len());
+            address + bytes.len()
+        };
+        copy(platform, self.platform.to_bytes_with_nul());
+        copy(random, &self.random);
+
+        let mut cursor = pointer as *mut usize;
+        let mut push = |word: usize| unsafe {
+            cursor.write(word);
+            cursor = cursor.add(1);
+        };
+
+        // Strings are copied in vector order, each entry pointing at its copy
+        let mut string = strings;
+        push(self.arguments.clone().count());
+        for argument in self.arguments.clone() {
+            push(string);
+            string = copy(string, argument.to_bytes_with_nul());
+        }
+        push(0);
+
+        for variable in self.environment.clone() {
+            push(string);
+            string = copy(string, variable.to_bytes_with_nul());
+        }
+        push(0);
+
+        let has_arguments = self.arguments.clone().next().is_some();
+        for entry in self.entries() {
+            let value = match Type::from(entry.atype) {
+                Type::Random => random,
+                Type::Platform => platform,
+                Type::ExecFn if has_arguments => strings,
+                _ => entry.value,
+            };
+            push(entry.atype);
+            push(value);
+        }
+        push(Type::Null.to());
+        push(0);
+
+        Some(pointer as *mut u64)
+    }
+}
@@ -0,0 +1,118 @@
+use std::ffi::CStr;
+
+use arch::memory::stack::builder::{ALIGNMENT, RANDOM_SIZE};
+use arch::memory::stack::{AuxEntry, Builder, Stack, Type};
+
+const ARGUMENTS: [&CStr; 2] = [c"/bin/program", c"--flag"];
+const ENVIRONMENT: [&CStr; 1] = [c"HOME=/root"];
+const RANDOM: [u8; RANDOM_SIZE] = [7; RANDOM_SIZE];
+
+fn entry(atype: Type, value: usize) -> AuxEntry {
+    AuxEntry {
+        atype: atype.to(),
+        value,
+    }
+}
+
+/// Words of a stack region, so the region itself is 8-byte aligned
+fn region(words: usize) -> Vec<u64> {
+    vec![0; words]
+}
+
+fn bytes(region: &mut [u64]) -> &mut [u8] {
+    unsafe { std::slice::from_raw_parts_mut(region.as_mut_ptr().cast(), region.len() * 8) }
+}
+
+#[test]
+fn stacks_are_laid_out_as_the_kernel_does() {
+    let auxiliary = [entry(Type::PageSz, 4096), entry(Type::ExecFn, 0)];
+    let builder = Builder::new(
+        ARGUMENTS.into_iter(),
+        ENVIRONMENT.into_iter(),
+        auxiliary.into_iter(),
+        RANDOM,
+    );
+
+    // The strings above the vectors take 54 bytes, so the pointer has to be rounded down
+    let mut region = region(129);
+    let region = bytes(&mut region);
+    let (start, end) = (
+        region.as_ptr() as usize,
+        region.as_ptr() as usize + region.len(),
+    );
+    let pointer = builder.write(region).unwrap();
+    assert_eq!(pointer as usize % ALIGNMENT, 0);
+
+    let words = pointer as *const usize;
+    let word = |index: usize| unsafe { *words.add(index) };
+    let string = |address: usize| unsafe { CStr::from_ptr(address as *const _) };
+    let within = |address: usize| (start..end).contains(&address);
+
+    // argc, then argv and envp, each closed by a null pointer
+    assert_eq!(word(0), ARGUMENTS.len());
+    assert_eq!(string(word(1)), ARGUMENTS[0]);
+    assert_eq!(string(word(2)), ARGUMENTS[1]);
+    assert_eq!(word(3), 0);
+    assert_eq!(string(word(4)), ENVIRONMENT[0]);
+    assert_eq!(word(5), 0);
+    assert!([1, 2, 4].into_iter().all(|index| within(word(index))));
+
+    // The given entries come first, then the missing `AT_RANDOM` and `AT_PLATFORM`
+    let auxv: Vec<_> = (6..16)
+        .step_by(2)
+        .map(|index| (word(index), word(index + 1)))
+        .collect();
+    assert_eq!(auxv[0], (Type::PageSz.to(), 4096));
+    assert_eq!(auxv[1], (Type::ExecFn.to(), word(1)));
+    assert_eq!(auxv[2].0, Type::Random.to());
+    assert_eq!(auxv[3].0, Type::Platform.to());
+    assert_eq!(auxv[4], (Type::Null.to(), 0));
+
+    let random = unsafe { std::slice::from_raw_parts(auxv[2].1 as *const u8, RANDOM_SIZE) };
+    assert_eq!(random, RANDOM);
+    assert_eq!(string(auxv[3].1), arch::PLATFORM);
+    assert!(within(auxv[2].1) && within(auxv[3].1));
+}
+
+#[test]
+fn built_stacks_read_back_through_stack() {
+    let auxiliary = [entry(Type::Entry, 0x401000)];
+    let builder = Builder::new(
+        ARGUMENTS.into_iter(),
+        ENVIRONMENT.into_iter(),
+        auxiliary.into_iter(),
+        RANDOM,
+    )
+    .platform(c"haswell");
+
+    let mut region = region(128);
+    let pointer = builder.write(bytes(&mut region)).unwrap();
+    let stack = unsafe { Stack::from_pointer(pointer) };
+
+    assert!(stack.arguments().eq(ARGUMENTS));
+    assert!(stack.environment().eq(ENVIRONMENT));
+
+    let entries: Vec<_> = stack
+        .auxiliary()
+        .map(|entry| Type::from(entry.atype))
+        .collect();
+    assert!(matches!(
+        entries[..],
+        [Type::Entry, Type::Random, Type::Platform]
+    ));
+    let platform = stack.auxiliary().nth(2).unwrap().value;
+    assert_eq!(unsafe { CStr::from_ptr(platform as *const _) }, c"haswell");
+}
+
+#[test]
+fn regions_smaller_than_the_stack_are_refused() {
+    let builder = Builder::new(
+        ARGUMENTS.into_iter(),
+        ENVIRONMENT.into_iter(),
+        [].into_iter(),
+        RANDOM,
+    );
+
+    let mut region = region(builder.size() / 8 - 1);
+    assert_eq!(builder.write(bytes(&mut region)), None);
+}
@@ -0,0 +1,264 @@
+pub mod flags;
+
+pub use flags::{Abi, Kind};
+
+use crate::header::Class;
+use crate::reader::{Endianness, Reader};
+use crate::result::{Error, Result};
+use crate::string;
+
+/// Magic of the format libc5's `ldconfig` wrote, still accepted by glibc
+pub const MAGIC: &[u8] = b"ld.so-1.7.0";
+
+/// Magic and version of the format glibc writes
+pub const MAGIC_NEW: &[u8] = b"glibc-ld.so.cache";
+pub const VERSION: &[u8] = b"1.1";
+
+/// Magic opening the extension area of the glibc format
+pub const EXTENSION_MAGIC: u32 = 0xeaa4_2174;
+
+/// Extension section listing the `glibc-hwcaps` subdirectory names
+pub const EXTENSION_GLIBC_HWCAPS: u32 = 1;
+
+/// Set in an entry's `hwcap` when its low 32 bits index the `glibc-hwcaps` names
+pub const HWCAP_EXTENSION: u64 = 1 << 62;
+
+const HEADER_SIZE: u64 = 16;
+const ENTRY_SIZE: u64 = 12;
+const HEADER_SIZE_NEW: u64 = 48;
+const ENTRY_SIZE_NEW: u64 = 24;
+const SECTION_SIZE: u64 = 16;
+
+/// Alignment of the glibc header when it follows entries of the old format
+const ALIGNMENT: u64 = 8;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    Legacy,
+    Glibc,
+}
+
+/// A library the cache knows: the name it is needed as and where it lives.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Entry<'a> {
+    pub flags: i32,     /* Kind and required ABI */
+    pub key: &'a str,   /* Name, usually a soname */
+    pub value: &'a str, /* Path of the library */
+    pub hwcap: u64,     /* Hardware capabilities needed, glibc format only */
+}
+
+impl Entry<'_> {
+    pub fn kind(&self) -> Kind {
+        Kind::from(self.flags & flags::FLAG_TYPE_MASK)
+    }
+
+    pub fn abi(&self) -> Abi {
+        Abi::from(self.flags & flags::FLAG_REQUIRED_MASK)
+    }
+
+    /// Whether a dynamic linker for `abi` may use the entry: a plain ELF entry, or a
+    /// libc6 one built for exactly that ABI
+    pub fn is_for(&self, abi: Abi) -> bool {
+        self.flags == flags::FLAG_ELF || self.flags == flags::FLAG_ELF_LIBC6 | abi.to()
+    }
+
+    /// Index of the `glibc-hwcaps` subdirectory the entry was found in
+    pub fn hwcaps_index(&self) -> Option<u32> {
+        (self.hwcap & HWCAP_EXTENSION != 0).then_some(self.hwcap as u32)
+    }
+}
+
+/// A dynamic linker cache such as `/etc/ld.so.cache`, in either format.
+///
+/// A file holding both formats, as older glibc wrote by default, is read through its
+/// glibc part.
+#[derive(Debug, Copy, Clone)]
+pub struct Cache<'a> {
+    pub format: Format,
+    reader: Reader<'a>, /* From the header in use, which string offsets count from */
+    count: u64,         /* Number of entries */
+    entries: u64,       /* Offset of the first entry */
+    strings: u64,       /* Offset string offsets are relative to */
+    hwcaps: (u64, u64), /* Offset and count of the `glibc-hwcaps` names */
+}
+
+impl<'a> Cache<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.starts_with(MAGIC) {
+            return Self::parse_legacy(bytes);
+        }
+
+        Self::parse_glibc(bytes)
+    }
+
+    fn parse_legacy(bytes: &'a [u8]) -> Result<Self> {
+        // The old format has no byte order marker and is always native
+        let reader = Reader::new(bytes, Class::Elf64, native());
+        let count = reader.u32(MAGIC.len() as u64 + 1)? as u64;
+        let end = HEADER_SIZE + count * ENTRY_SIZE;
+        reader.slice(HEADER_SIZE, count * ENTRY_SIZE)?;
+
+        let glibc = end.next_multiple_of(ALIGNMENT);
+        if let Some(rest) = bytes.get(glibc as usize..)
+            && rest.starts_with(MAGIC_NEW)
+        {
+            return Self::parse_glibc(rest);
+        }
+
+        Ok(Self {
+            format: Format::Legacy,
+            reader,
+            count,
+            entries: HEADER_SIZE,
+            strings: end,
+            hwcaps: (0, 0),
+        })
+    }
+
+    fn parse_glibc(bytes: &'a [u8]) -> Result<Self> {
+        let magic = MAGIC_NEW.len();
+        if bytes.get(..magic) != Some(MAGIC_NEW)
+            || bytes.get(magic..magic + VERSION.len()) != Some(VERSION)
+        {
+            return Err(Error::InvalidCache);
+        }
+
+        let endianness = match bytes.get(28).ok_or(Error::Truncated)? & 3 {
+            0 => native(),
+            2 => Endianness::Little,
+            3 => Endianness::Big,
+            _ => return Err(Error::InvalidCache),
+        };
+        let reader = Reader::new(bytes, Class::Elf64, endianness);
+        let count = reader.u32(20)? as u64;
+        reader.slice(HEADER_SIZE_NEW, count * ENTRY_SIZE_NEW)?;
+
+        let mut cache = Self {
+            format: Format::Glibc,
+            reader,
+            count,
+            entries: HEADER_SIZE_NEW,
+            strings: 0,
+            hwcaps: (0, 0),
+        };
+        cache.hwcaps = cache.find_hwcaps(reader.u32(32)? as u64)?;
+
+        Ok(cache)
+    }
+
+    /// Locate the `glibc-hwcaps` section of the extension area at `offset`, if any
+    fn find_hwcaps(&self, offset: u64) -> Result<(u64, u64)> {
+        if offset == 0 || self.reader.u32(offset)? != EXTENSION_MAGIC {
+            return Ok((0, 0));
+        }
+
+        for index in 0..self.reader.u32(offset + 4)? as u64 {
+            let section = offset + 8 + index * SECTION_SIZE;
+            if self.reader.u32(section)? == EXTENSION_GLIBC_HWCAPS {
+                let start = self.reader.u32(section + 8)? as u64;
+                let size = self.reader.u32(section + 12)? as u64;
+                self.reader.slice(start, size)?;
+
+                return Ok((start, size / 4));
+            }
+        }
+
+        Ok((0, 0))
+    }
+
+    pub fn len(&self) -> usize {
+        self.count as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn string(&self, offset: u32) -> Result<&'a str> {
+        let strings = self
+            .reader
+            .bytes
+            .get(self.strings as usize..)
+            .ok_or(Error::Truncated)?;
+        string::Table::new(strings).get(offset as u64)
+    }
+
+    pub fn get(&self, index: usize) -> Result<Entry<'a>> {
+        if index as u64 >= self.count {
+            return Err(Error::Truncated);
+        }
+
+        let (size, hwcap) = match self.format {
+            Format::Legacy => (ENTRY_SIZE, None),
+            Format::Glibc => (ENTRY_SIZE_NEW, Some(16)),
+        };
+        let offset = self.entries + index as u64 * size;
+
+        Ok(Entry {
+            flags: self.reader.u32(offset)? as i32,
+            key: self.string(self.reader.u32(offset + 4)?)?,
+            value: self.string(self.reader.u32(offset + 8)?)?,
+            hwcap: match hwcap {
+                Some(field) => self.reader.u64(offset + field)?,
+                None => 0,
+            },
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Result<Entry<'a>>> + '_ {
+        (0..self.len()).map(|index| self.get(index))
+    }
+
+    /// Name of the `glibc-hwcaps` subdirectory at `index`, such as `x86-64-v3`
+    pub fn hwcaps(&self, index: u32) -> Result<&'a str> {
+        let (offset, count) = self.hwcaps;
+        if index as u64 >= count {
+            return Err(Error::Truncated);
+        }
+
+        self.string(self.reader.u32(offset + index as u64 * 4)?)
+    }
+
+    /// Path of the best library cached as `name` for `abi`.
+    ///
+    /// `supported` lists the `glibc-hwcaps` subdirectories the machine can use, best
+    /// first; an entry from one of them wins over one outside any, and entries from
+    /// other subdirectories are left out. Entries that need legacy hardware
+    /// capabilities are left out as well, as glibc no longer honours them.
+    pub fn lookup(&self, name: &str, abi: Abi, supported: &[&str]) -> Result<Option<Entry<'a>>> {
+        let mut best: Option<(usize, Entry<'a>)> = None;
+
+        for entry in self.iter() {
+            let entry = entry?;
+            if entry.key != name || !entry.is_for(abi) {
+                continue;
+            }
+
+            let priority = match entry.hwcaps_index() {
+                Some(index) => {
+                    let subdirectory = self.hwcaps(index)?;
+                    match supported.iter().position(|name| *name == subdirectory) {
+                        Some(position) => position,
+                        None => continue,
+                    }
+                }
+                None if entry.hwcap == 0 => supported.len(),
+                None => continue,
+            };
+
+            if best.is_none_or(|(best, _)| priority < best) {
+                best = Some((priority, entry));
+            }
+        }
+
+        Ok(best.map(|(_, entry)| entry))
+    }
+}
+
+fn native() -> Endianness {
+    if cfg!(target_endian = "big") {
+        Endianness::Big
+    } else {
+        Endianness::Little
+    }
+}
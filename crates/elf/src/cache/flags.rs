@@ -0,0 +1,77 @@
+/// Bits of an entry's flags holding its kind
+pub const FLAG_TYPE_MASK: i32 = 0x00ff;
+
+/// Bits of an entry's flags holding the ABI it requires
+pub const FLAG_REQUIRED_MASK: i32 = 0xff00;
+
+define_type!(
+    Kind,
+    i32,
+    [
+        [Libc4, 0x0000, "a.out library for libc4", FLAG_LIBC4],
+        [Elf, 0x0001, "ELF library", FLAG_ELF],
+        [ElfLibc5, 0x0002, "ELF library for libc5", FLAG_ELF_LIBC5],
+        [ElfLibc6, 0x0003, "ELF library for libc6", FLAG_ELF_LIBC6],
+    ]
+);
+
+define_type!(
+    Abi,
+    i32,
+    [
+        [None, 0x0000, "No particular ABI", FLAG_ABI_NONE],
+        [SparcLib64, 0x0100, "64-bit SPARC", FLAG_SPARC_LIB64],
+        [Ia64Lib64, 0x0200, "IA-64", FLAG_IA64_LIB64],
+        [X8664Lib64, 0x0300, "x86_64", FLAG_X8664_LIB64],
+        [S390Lib64, 0x0400, "64-bit S/390", FLAG_S390_LIB64],
+        [PowerPcLib64, 0x0500, "64-bit PowerPC", FLAG_POWERPC_LIB64],
+        [Mips64LibN32, 0x0600, "MIPS64 n32", FLAG_MIPS64_LIBN32],
+        [Mips64LibN64, 0x0700, "MIPS64 n64", FLAG_MIPS64_LIBN64],
+        [X8664LibX32, 0x0800, "x32", FLAG_X8664_LIBX32],
+        [ArmLibHf, 0x0900, "ARM hard-float", FLAG_ARM_LIBHF],
+        [AArch64Lib64, 0x0a00, "AArch64", FLAG_AARCH64_LIB64],
+        [ArmLibSf, 0x0b00, "ARM soft-float", FLAG_ARM_LIBSF],
+        [
+            MipsLib32Nan2008,
+            0x0c00,
+            "MIPS o32 with 2008 NaN",
+            FLAG_MIPS_LIB32_NAN2008
+        ],
+        [
+            Mips64LibN32Nan2008,
+            0x0d00,
+            "MIPS64 n32 with 2008 NaN",
+            FLAG_MIPS64_LIBN32_NAN2008
+        ],
+        [
+            Mips64LibN64Nan2008,
+            0x0e00,
+            "MIPS64 n64 with 2008 NaN",
+            FLAG_MIPS64_LIBN64_NAN2008
+        ],
+        [
+            RiscvFloatAbiSoft,
+            0x0f00,
+            "RISC-V soft-float",
+            FLAG_RISCV_FLOAT_ABI_SOFT
+        ],
+        [
+            RiscvFloatAbiDouble,
+            0x1000,
+            "RISC-V double-float",
+            FLAG_RISCV_FLOAT_ABI_DOUBLE
+        ],
+        [
+            LarchFloatAbiSoft,
+            0x1100,
+            "LoongArch soft-float",
+            FLAG_LARCH_FLOAT_ABI_SOFT
+        ],
+        [
+            LarchFloatAbiDouble,
+            0x1200,
+            "LoongArch double-float",
+            FLAG_LARCH_FLOAT_ABI_DOUBLE
+        ],
+    ]
+);
@@ -0,0 +1,126 @@
+pub mod flag;
+pub mod flag1;
+pub mod tag;
+
+pub use flag::{Flag, Flags};
+pub use flag1::{Flag1, Flags1};
+pub use tag::Tag;
+
+use crate::reader::Reader;
+use crate::result::{Error, Result};
+use crate::string;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Entry {
+    pub tag: Tag,   /* Dynamic entry type */
+    pub value: u64, /* Integer or address value */
+}
+
+impl crate::table::Entry for Entry {
+    /// Decode the `Elf32_Dyn` or `Elf64_Dyn` found at `offset`
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        if reader.is_32() {
+            return Ok(Self {
+                tag: Tag::from(reader.u32(offset)? as i32 as i64),
+                value: reader.u32(offset + 4)? as u64,
+            });
+        }
+
+        Ok(Self {
+            tag: Tag::from(reader.u64(offset)? as i64),
+            value: reader.u64(offset + 8)?,
+        })
+    }
+}
+
+/// The raw `.dynamic` array, including any padding after `DT_NULL`
+pub type Table<'a> = crate::table::Table<'a, Entry>;
+
+/// The dynamic array together with the `DT_STRTAB` string table its string-valued tags refer to.
+#[derive(Debug, Copy, Clone)]
+pub struct Dynamic<'a> {
+    pub table: Table<'a>,
+    pub strings: Option<string::Table<'a>>,
+}
+
+impl<'a> Dynamic<'a> {
+    pub fn new(table: Table<'a>, strings: Option<string::Table<'a>>) -> Self {
+        Self { table, strings }
+    }
+
+    /// Entries up to the terminating `DT_NULL`
+    pub fn iter(&self) -> impl Iterator<Item = Entry> + 'a {
+        self.table.iter().take_while(|entry| entry.tag != Tag::Null)
+    }
+
+    /// Value of the first entry with the given tag
+    pub fn get(&self, tag: Tag) -> Option<u64> {
+        self.iter()
+            .find(|entry| entry.tag == tag)
+            .map(|entry| entry.value)
+    }
+
+    pub fn contains(&self, tag: Tag) -> bool {
+        self.get(tag).is_some()
+    }
+
+    /// Resolve a string-valued entry such as `DT_NEEDED` through `DT_STRTAB`
+    pub fn string(&self, entry: &Entry) -> Result<&'a str> {
+        if !entry.tag.is_string() {
+            return Err(Error::NotAString);
+        }
+
+        self.strings
+            .ok_or(Error::MissingDynamicStrings)?
+            .get(entry.value)
+    }
+
+    /// String of the first entry with the given tag
+    pub fn get_string(&self, tag: Tag) -> Result<Option<&'a str>> {
+        match self.iter().find(|entry| entry.tag == tag) {
+            Some(entry) => self.string(&entry).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Names of the libraries listed by `DT_NEEDED`, in order
+    pub fn needed(&self) -> impl Iterator<Item = Result<&'a str>> + 'a {
+        let dynamic = *self;
+
+        self.iter()
+            .filter(|entry| entry.tag == Tag::Needed)
+            .map(move |entry| dynamic.string(&entry))
+    }
+
+    pub fn soname(&self) -> Result<Option<&'a str>> {
+        self.get_string(Tag::SoName)
+    }
+
+    pub fn rpath(&self) -> Result<Option<&'a str>> {
+        self.get_string(Tag::RPath)
+    }
+
+    pub fn runpath(&self) -> Result<Option<&'a str>> {
+        self.get_string(Tag::RunPath)
+    }
+
+    pub fn flags(&self) -> Flags {
+        Flags::from(self.get(Tag::Flags).unwrap_or(0))
+    }
+
+    pub fn flags_1(&self) -> Flags1 {
+        Flags1::from(self.get(Tag::Flags1).unwrap_or(0))
+    }
+
+    /// Whether the object asks for all relocations to be processed at load time
+    pub fn bind_now(&self) -> bool {
+        self.contains(Tag::BindNow)
+            || self.flags().contains(Flag::BindNow)
+            || self.flags_1().contains(Flag1::Now)
+    }
+
+    /// Whether the object's own definitions come before the global scope for its references
+    pub fn is_symbolic(&self) -> bool {
+        self.contains(Tag::Symbolic) || self.flags().contains(Flag::Symbolic)
+    }
+}
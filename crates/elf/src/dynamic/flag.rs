@@ -0,0 +1,17 @@
+define_flags!(
+    Flags,
+    Flag,
+    u64,
+    [
+        [Origin, 0x1, "Object may use DF_ORIGIN", DF_ORIGIN],
+        [Symbolic, 0x2, "Symbol resolutions starts here", DF_SYMBOLIC],
+        [TextRel, 0x4, "Object contains text relocations", DF_TEXTREL],
+        [BindNow, 0x8, "No lazy binding for this object", DF_BIND_NOW],
+        [
+            StaticTls,
+            0x10,
+            "Module uses the static TLS model",
+            DF_STATIC_TLS
+        ],
+    ]
+);
@@ -0,0 +1,80 @@
+define_flags!(
+    Flags1,
+    Flag1,
+    u64,
+    [
+        [Now, 0x1, "Set RTLD_NOW for this object", DF_1_NOW],
+        [Global, 0x2, "Set RTLD_GLOBAL for this object", DF_1_GLOBAL],
+        [Group, 0x4, "Set RTLD_GROUP for this object", DF_1_GROUP],
+        [
+            NoDelete,
+            0x8,
+            "Set RTLD_NODELETE for this object",
+            DF_1_NODELETE
+        ],
+        [
+            LoadFltr,
+            0x10,
+            "Trigger filtee loading at runtime",
+            DF_1_LOADFLTR
+        ],
+        [
+            InitFirst,
+            0x20,
+            "Set RTLD_INITFIRST for this object",
+            DF_1_INITFIRST
+        ],
+        [NoOpen, 0x40, "Set RTLD_NOOPEN for this object", DF_1_NOOPEN],
+        [Origin, 0x80, "$ORIGIN must be handled", DF_1_ORIGIN],
+        [Direct, 0x100, "Direct binding enabled", DF_1_DIRECT],
+        [
+            Interpose,
+            0x400,
+            "Object is used to interpose",
+            DF_1_INTERPOSE
+        ],
+        [
+            NoDefLib,
+            0x800,
+            "Ignore default lib search path",
+            DF_1_NODEFLIB
+        ],
+        [NoDump, 0x1000, "Object can't be dldump'ed", DF_1_NODUMP],
+        [
+            ConfAlt,
+            0x2000,
+            "Configuration alternative created",
+            DF_1_CONFALT
+        ],
+        [
+            EndFiltee,
+            0x4000,
+            "Filtee terminates filters search",
+            DF_1_ENDFILTEE
+        ],
+        [
+            DispRelDne,
+            0x8000,
+            "Disp reloc applied at build time",
+            DF_1_DISPRELDNE
+        ],
+        [
+            DispRelPnd,
+            0x10000,
+            "Disp reloc applied at run-time",
+            DF_1_DISPRELPND
+        ],
+        [
+            NoDirect,
+            0x20000,
+            "Object has no-direct binding",
+            DF_1_NODIRECT
+        ],
+        [
+            Pie,
+            0x08000000,
+            "Object is a position-independent executable",
+            DF_1_PIE
+        ],
+    ]
+);
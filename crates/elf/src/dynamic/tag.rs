@@ -0,0 +1,230 @@
+define_type!(
+    Tag,
+    i64,
+    [
+        [Null, 0, "Marks end of dynamic section", DT_NULL],
+        [Needed, 1, "Name of needed library", DT_NEEDED],
+        [PltRelSz, 2, "Size in bytes of PLT relocs", DT_PLTRELSZ],
+        [PltGot, 3, "Processor defined value", DT_PLTGOT],
+        [Hash, 4, "Address of symbol hash table", DT_HASH],
+        [StrTab, 5, "Address of string table", DT_STRTAB],
+        [SymTab, 6, "Address of symbol table", DT_SYMTAB],
+        [Rela, 7, "Address of Rela relocs", DT_RELA],
+        [RelaSz, 8, "Total size of Rela relocs", DT_RELASZ],
+        [RelaEnt, 9, "Size of one Rela reloc", DT_RELAENT],
+        [StrSz, 10, "Size of string table", DT_STRSZ],
+        [SymEnt, 11, "Size of one symbol table entry", DT_SYMENT],
+        [Init, 12, "Address of init function", DT_INIT],
+        [Fini, 13, "Address of termination function", DT_FINI],
+        [SoName, 14, "Name of shared object", DT_SONAME],
+        [RPath, 15, "Library search path (deprecated)", DT_RPATH],
+        [Symbolic, 16, "Start symbol search here", DT_SYMBOLIC],
+        [Rel, 17, "Address of Rel relocs", DT_REL],
+        [RelSz, 18, "Total size of Rel relocs", DT_RELSZ],
+        [RelEnt, 19, "Size of one Rel reloc", DT_RELENT],
+        [PltRel, 20, "Type of reloc in PLT", DT_PLTREL],
+        [Debug, 21, "For debugging; unspecified", DT_DEBUG],
+        [TextRel, 22, "Reloc might modify .text", DT_TEXTREL],
+        [JmpRel, 23, "Address of PLT relocs", DT_JMPREL],
+        [BindNow, 24, "Process relocations of object", DT_BIND_NOW],
+        [
+            InitArray,
+            25,
+            "Array with addresses of init fct",
+            DT_INIT_ARRAY
+        ],
+        [
+            FiniArray,
+            26,
+            "Array with addresses of fini fct",
+            DT_FINI_ARRAY
+        ],
+        [
+            InitArraySz,
+            27,
+            "Size in bytes of DT_INIT_ARRAY",
+            DT_INIT_ARRAYSZ
+        ],
+        [
+            FiniArraySz,
+            28,
+            "Size in bytes of DT_FINI_ARRAY",
+            DT_FINI_ARRAYSZ
+        ],
+        [RunPath, 29, "Library search path", DT_RUNPATH],
+        [Flags, 30, "Flags for the object being loaded", DT_FLAGS],
+        [
+            PreInitArray,
+            32,
+            "Array with addresses of preinit fct",
+            DT_PREINIT_ARRAY
+        ],
+        [
+            PreInitArraySz,
+            33,
+            "Size in bytes of DT_PREINIT_ARRAY",
+            DT_PREINIT_ARRAYSZ
+        ],
+        [
+            SymTabShndx,
+            34,
+            "Address of SYMTAB_SHNDX section",
+            DT_SYMTAB_SHNDX
+        ],
+        [
+            RelrSz,
+            35,
+            "Total size of RELR relative relocations",
+            DT_RELRSZ
+        ],
+        [Relr, 36, "Address of RELR relative relocations", DT_RELR],
+        [
+            RelrEnt,
+            37,
+            "Size of one RELR relative relocaction",
+            DT_RELRENT
+        ],
+        [
+            AndroidRel,
+            0x6000000f,
+            "Address of Android packed Rel relocs",
+            DT_ANDROID_REL
+        ],
+        [
+            AndroidRelSz,
+            0x60000010,
+            "Total size of Android packed Rel relocs",
+            DT_ANDROID_RELSZ
+        ],
+        [
+            AndroidRela,
+            0x60000011,
+            "Address of Android packed Rela relocs",
+            DT_ANDROID_RELA
+        ],
+        [
+            AndroidRelaSz,
+            0x60000012,
+            "Total size of Android packed Rela relocs",
+            DT_ANDROID_RELASZ
+        ],
+        [
+            AndroidRelr,
+            0x6fffe000,
+            "Address of Android RELR relative relocations",
+            DT_ANDROID_RELR
+        ],
+        [
+            AndroidRelrSz,
+            0x6fffe001,
+            "Total size of Android RELR relative relocations",
+            DT_ANDROID_RELRSZ
+        ],
+        [
+            AndroidRelrEnt,
+            0x6fffe003,
+            "Size of one Android RELR relative relocation",
+            DT_ANDROID_RELRENT
+        ],
+        [GnuHash, 0x6ffffef5, "GNU-style hash table", DT_GNU_HASH],
+        [
+            VerSym,
+            0x6ffffff0,
+            "Address of symbol version table",
+            DT_VERSYM
+        ],
+        [
+            RelaCount,
+            0x6ffffff9,
+            "Number of relative Rela relocs",
+            DT_RELACOUNT
+        ],
+        [
+            RelCount,
+            0x6ffffffa,
+            "Number of relative Rel relocs",
+            DT_RELCOUNT
+        ],
+        [Flags1, 0x6ffffffb, "State flags", DT_FLAGS_1],
+        [
+            VerDef,
+            0x6ffffffc,
+            "Address of version definition table",
+            DT_VERDEF
+        ],
+        [
+            VerDefNum,
+            0x6ffffffd,
+            "Number of version definitions",
+            DT_VERDEFNUM
+        ],
+        [
+            VerNeed,
+            0x6ffffffe,
+            "Address of table with needed versions",
+            DT_VERNEED
+        ],
+        [
+            VerNeedNum,
+            0x6fffffff,
+            "Number of needed versions",
+            DT_VERNEEDNUM
+        ],
+        [
+            Auxiliary,
+            0x7ffffffd,
+            "Shared object to load before self",
+            DT_AUXILIARY
+        ],
+        [
+            Filter,
+            0x7fffffff,
+            "Shared object to get values from",
+            DT_FILTER
+        ],
+    ]
+);
+
+impl Tag {
+    /// Whether the value is an offset into the `DT_STRTAB` string table
+    pub fn is_string(&self) -> bool {
+        matches!(
+            self,
+            Self::Needed
+                | Self::SoName
+                | Self::RPath
+                | Self::RunPath
+                | Self::Auxiliary
+                | Self::Filter
+        )
+    }
+
+    /// Whether the value is a virtual address rather than a size or number
+    pub fn is_pointer(&self) -> bool {
+        matches!(
+            self,
+            Self::PltGot
+                | Self::Hash
+                | Self::StrTab
+                | Self::SymTab
+                | Self::Rela
+                | Self::Init
+                | Self::Fini
+                | Self::Rel
+                | Self::Debug
+                | Self::JmpRel
+                | Self::InitArray
+                | Self::FiniArray
+                | Self::PreInitArray
+                | Self::SymTabShndx
+                | Self::Relr
+                | Self::AndroidRel
+                | Self::AndroidRela
+                | Self::AndroidRelr
+                | Self::GnuHash
+                | Self::VerSym
+                | Self::VerDef
+                | Self::VerNeed
+        )
+    }
+}
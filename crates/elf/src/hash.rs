@@ -0,0 +1,35 @@
+pub mod gnu;
+pub mod sysv;
+
+use crate::result::Result;
+use crate::symbol::{Symbol, Symbols};
+
+/// The symbol hash table of an object, `DT_GNU_HASH` being preferred over `DT_HASH`.
+#[derive(Debug, Copy, Clone)]
+pub enum Hash<'a> {
+    Gnu(gnu::Table<'a>),
+    Sysv(sysv::Table<'a>),
+}
+
+impl Hash<'_> {
+    /// Number of entries in the symbol table the hash covers
+    pub fn symbol_count(&self) -> Result<usize> {
+        match self {
+            Self::Gnu(table) => table.symbol_count(),
+            Self::Sysv(table) => Ok(table.symbol_count()),
+        }
+    }
+
+    /// Find a defined symbol by name among those `accept` agrees to
+    pub fn lookup(
+        &self,
+        name: &str,
+        symbols: &Symbols,
+        accept: impl FnMut(usize, &Symbol) -> bool,
+    ) -> Option<(usize, Symbol)> {
+        match self {
+            Self::Gnu(table) => table.lookup(name, symbols, accept),
+            Self::Sysv(table) => table.lookup(name, symbols, accept),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::symbol::{Symbol, Symbols};
+
+/// The hash function used by `DT_GNU_HASH`
+pub fn hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |hash, &byte| {
+        hash.wrapping_mul(33).wrapping_add(byte as u32)
+    })
+}
+
+/// A `DT_GNU_HASH` table: a bloom filter in front of buckets of contiguous, sorted chains.
+#[derive(Debug, Copy, Clone)]
+pub struct Table<'a> {
+    reader: Reader<'a>,
+    offset: u64,
+    pub nbuckets: u32,
+    pub symoffset: u32,
+    pub bloom_size: u32,
+    pub bloom_shift: u32,
+}
+
+impl<'a> Table<'a> {
+    pub fn parse(reader: Reader<'a>, offset: u64) -> Result<Self> {
+        let table = Self {
+            reader,
+            offset,
+            nbuckets: reader.u32(offset)?,
+            symoffset: reader.u32(offset + 4)?,
+            bloom_size: reader.u32(offset + 8)?,
+            bloom_shift: reader.u32(offset + 12)?,
+        };
+
+        reader.slice(offset, table.chains_offset() - offset)?;
+
+        // The second bloom bit comes from the hash shifted right by this much
+        if table.bloom_shift >= u32::BITS {
+            return Err(crate::Error::Malformed);
+        }
+
+        Ok(table)
+    }
+
+    /// Bits in one bloom filter word
+    fn word_bits(&self) -> u32 {
+        self.reader.addr_size() as u32 * 8
+    }
+
+    fn bloom(&self, index: u32) -> Result<u64> {
+        self.reader
+            .addr(self.offset + 16 + self.reader.addr_size() * index as u64)
+    }
+
+    fn buckets_offset(&self) -> u64 {
+        self.offset + 16 + self.reader.addr_size() * self.bloom_size as u64
+    }
+
+    fn chains_offset(&self) -> u64 {
+        self.buckets_offset() + 4 * self.nbuckets as u64
+    }
+
+    fn bucket(&self, index: u32) -> Result<u32> {
+        self.reader.u32(self.buckets_offset() + 4 * index as u64)
+    }
+
+    fn chain(&self, index: u32) -> Result<u32> {
+        let position = index
+            .checked_sub(self.symoffset)
+            .ok_or(crate::Error::Malformed)?;
+        self.reader.u32(self.chains_offset() + 4 * position as u64)
+    }
+
+    /// Whether the bloom filter rules the hash out
+    fn rejects(&self, hash: u32) -> bool {
+        if self.bloom_size == 0 {
+            return true;
+        }
+
+        let bits = self.word_bits();
+        let Ok(word) = self.bloom((hash / bits) % self.bloom_size) else {
+            return true;
+        };
+        let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> self.bloom_shift) % bits));
+
+        word & mask != mask
+    }
+
+    /// Number of entries in the symbol table the hash covers
+    pub fn symbol_count(&self) -> Result<usize> {
+        let last = (0..self.nbuckets)
+            .map(|index| self.bucket(index))
+            .try_fold(0, |last, bucket| bucket.map(|bucket| last.max(bucket)))?;
+
+        if last < self.symoffset {
+            return Ok(self.symoffset as usize);
+        }
+
+        // The chain of the highest bucket ends at the last symbol
+        let mut index = last;
+        while self.chain(index)? & 1 == 0 {
+            index += 1;
+        }
+
+        Ok(index as usize + 1)
+    }
+
+    /// Find a defined symbol by name, consulting the bloom filter before the chain
+    pub fn lookup(
+        &self,
+        name: &str,
+        symbols: &Symbols,
+        mut accept: impl FnMut(usize, &Symbol) -> bool,
+    ) -> Option<(usize, Symbol)> {
+        let hash = hash(name.as_bytes());
+
+        if self.nbuckets == 0 || self.rejects(hash) {
+            return None;
+        }
+
+        let mut index = self.bucket(hash % self.nbuckets).ok()?;
+        if index < self.symoffset {
+            return None;
+        }
+
+        loop {
+            let chain = self.chain(index).ok()?;
+
+            if (chain | 1) == (hash | 1) {
+                let symbol = symbols.get(index as usize)?;
+
+                if symbol.is_defined()
+                    && symbols.name(&symbol) == Ok(name)
+                    && accept(index as usize, &symbol)
+                {
+                    return Some((index as usize, symbol));
+                }
+            }
+
+            if chain & 1 != 0 {
+                return None;
+            }
+
+            index += 1;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::symbol::{Symbol, Symbols};
+
+/// The System V hash function used by `DT_HASH`
+pub fn hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |hash, &byte| {
+        let hash = (hash << 4).wrapping_add(byte as u32);
+        let high = hash & 0xf0000000;
+        (hash ^ (high >> 24)) & !high
+    })
+}
+
+/// A `DT_HASH` table: buckets of chains threaded through the symbol indices.
+#[derive(Debug, Copy, Clone)]
+pub struct Table<'a> {
+    reader: Reader<'a>,
+    offset: u64,
+    pub nbucket: u32,
+    pub nchain: u32,
+}
+
+impl<'a> Table<'a> {
+    pub fn parse(reader: Reader<'a>, offset: u64) -> Result<Self> {
+        let nbucket = reader.u32(offset)?;
+        let nchain = reader.u32(offset + 4)?;
+
+        reader.slice(offset, 8 + 4 * (nbucket as u64 + nchain as u64))?;
+
+        Ok(Self {
+            reader,
+            offset,
+            nbucket,
+            nchain,
+        })
+    }
+
+    /// Number of entries in the symbol table the hash covers
+    pub fn symbol_count(&self) -> usize {
+        self.nchain as usize
+    }
+
+    fn bucket(&self, index: u32) -> Result<u32> {
+        self.reader.u32(self.offset + 8 + 4 * index as u64)
+    }
+
+    fn chain(&self, index: u32) -> Result<u32> {
+        self.reader
+            .u32(self.offset + 8 + 4 * (self.nbucket as u64 + index as u64))
+    }
+
+    /// Find a defined symbol by name, walking the chain of its bucket
+    pub fn lookup(
+        &self,
+        name: &str,
+        symbols: &Symbols,
+        mut accept: impl FnMut(usize, &Symbol) -> bool,
+    ) -> Option<(usize, Symbol)> {
+        if self.nbucket == 0 {
+            return None;
+        }
+
+        let mut index = self.bucket(hash(name.as_bytes()) % self.nbucket).ok()?;
+        let mut steps = 0;
+
+        while index != 0 && steps < self.nchain {
+            let symbol = symbols.get(index as usize)?;
+
+            if symbol.is_defined()
+                && symbols.name(&symbol) == Ok(name)
+                && accept(index as usize, &symbol)
+            {
+                return Some((index as usize, symbol));
+            }
+
+            index = self.chain(index).ok()?;
+            steps += 1;
+        }
+
+        None
+    }
+}
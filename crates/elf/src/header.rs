@@ -0,0 +1,90 @@
+pub mod etype;
+pub mod ident;
+pub mod machine;
+
+pub use etype::Type;
+pub use ident::{Class, Data, Ident, OsAbi};
+pub use machine::Machine;
+
+use crate::result::{Error, Result};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Header {
+    pub ident: Ident,     /* ELF identification */
+    pub etype: Type,      /* Object file type */
+    pub machine: Machine, /* Machine type */
+    pub version: u32,     /* Object file version */
+    pub entry: u64,       /* Entry point address */
+    pub phoff: u64,       /* Program header offset in file */
+    pub shoff: u64,       /* Section header offset in file */
+    pub flags: u32,       /* Processor-specific flags */
+    pub ehsize: u16,      /* ELF header size */
+    pub phentsize: u16,   /* Size of program header entry */
+    pub phnum: u16,       /* Number of program header entries */
+    pub shentsize: u16,   /* Size of section header entry */
+    pub shnum: u16,       /* Number of section header entries */
+    pub shstrndx: u16,    /* Section name string table index */
+}
+
+impl Header {
+    /// Parse and validate the file header at the start of `bytes`, in either class and byte order
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let ident = Ident::parse(bytes)?;
+        let reader = ident.reader(bytes);
+
+        // Entry, program and section header offsets are class-sized, shifting what follows
+        let size = reader.addr_size();
+        let tail = 24 + 3 * size;
+
+        let header = Self {
+            ident,
+            etype: Type::from(reader.u16(16)?),
+            machine: Machine::from(reader.u16(18)?),
+            version: reader.u32(20)?,
+            entry: reader.addr(24)?,
+            phoff: reader.addr(24 + size)?,
+            shoff: reader.addr(24 + 2 * size)?,
+            flags: reader.u32(tail)?,
+            ehsize: reader.u16(tail + 4)?,
+            phentsize: reader.u16(tail + 6)?,
+            phnum: reader.u16(tail + 8)?,
+            shentsize: reader.u16(tail + 10)?,
+            shnum: reader.u16(tail + 12)?,
+            shstrndx: reader.u16(tail + 14)?,
+        };
+
+        header.validate()?;
+
+        Ok(header)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let class = self.ident.class;
+
+        if self.version != ident::EV_CURRENT as u32 {
+            return Err(Error::InvalidVersion);
+        }
+
+        if self.ehsize < class.header_size() {
+            return Err(Error::InvalidHeaderSize);
+        }
+
+        if self.phoff != 0 && self.phentsize != class.program_header_size() {
+            return Err(Error::InvalidProgramHeaderSize);
+        }
+
+        if self.shoff != 0 && self.shentsize != class.section_header_size() {
+            return Err(Error::InvalidSectionHeaderSize);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.etype == Type::Exec
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        self.etype == Type::Dyn
+    }
+}
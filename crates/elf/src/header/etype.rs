@@ -0,0 +1,11 @@
+define_type!(
+    Type,
+    u16,
+    [
+        [None, 0, "No file type", ET_NONE],
+        [Rel, 1, "Relocatable file", ET_REL],
+        [Exec, 2, "Executable file", ET_EXEC],
+        [Dyn, 3, "Shared object file", ET_DYN],
+        [Core, 4, "Core file", ET_CORE],
+    ]
+);
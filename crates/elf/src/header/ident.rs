@@ -0,0 +1,81 @@
+pub mod class;
+pub mod data;
+pub mod osabi;
+
+pub use class::Class;
+pub use data::Data;
+pub use osabi::OsAbi;
+
+use crate::reader::{Endianness, Reader};
+use crate::result::{Error, Result};
+
+pub const SIZE: usize = 16;
+pub const MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+
+pub const EV_CURRENT: u8 = 1;
+
+pub const EI_CLASS: usize = 4;
+pub const EI_DATA: usize = 5;
+pub const EI_VERSION: usize = 6;
+pub const EI_OSABI: usize = 7;
+pub const EI_ABIVERSION: usize = 8;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Ident {
+    pub class: Class,    /* File class */
+    pub data: Data,      /* Data encoding */
+    pub version: u8,     /* File version */
+    pub os_abi: OsAbi,   /* Operating system/ABI identification */
+    pub abi_version: u8, /* ABI version */
+}
+
+impl Ident {
+    /// Validate and decode `e_ident` from the start of an object
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let ident = bytes.get(..SIZE).ok_or(Error::Truncated)?;
+
+        if ident[..MAGIC.len()] != MAGIC {
+            return Err(Error::InvalidMagic);
+        }
+
+        let class = Class::from(ident[EI_CLASS]);
+        if !class.is_known() || class == Class::None {
+            return Err(Error::InvalidClass);
+        }
+
+        let data = Data::from(ident[EI_DATA]);
+        if !data.is_known() || data == Data::None {
+            return Err(Error::InvalidData);
+        }
+
+        let version = ident[EI_VERSION];
+        if version != EV_CURRENT {
+            return Err(Error::InvalidVersion);
+        }
+
+        let os_abi = OsAbi::from(ident[EI_OSABI]);
+        if !os_abi.is_known() {
+            return Err(Error::InvalidOsAbi);
+        }
+
+        Ok(Self {
+            class,
+            data,
+            version,
+            os_abi,
+            abi_version: ident[EI_ABIVERSION],
+        })
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        match self.data {
+            Data::Msb => Endianness::Big,
+            _ => Endianness::Little,
+        }
+    }
+
+    /// A reader over `bytes` in the class and byte order this identification declares
+    pub fn reader<'a>(&self, bytes: &'a [u8]) -> Reader<'a> {
+        Reader::new(bytes, self.class, self.endianness())
+    }
+}
@@ -0,0 +1,43 @@
+define_type!(
+    Class,
+    u8,
+    [
+        [None, 0, "Invalid class", ELFCLASSNONE],
+        [Elf32, 1, "32-bit objects", ELFCLASS32],
+        [Elf64, 2, "64-bit objects", ELFCLASS64],
+    ]
+);
+
+impl Class {
+    /// Size in bytes of addresses, offsets and extended words
+    pub fn addr_size(&self) -> u64 {
+        match self {
+            Self::Elf32 => 4,
+            _ => 8,
+        }
+    }
+
+    /// Size of `Elf32_Ehdr` or `Elf64_Ehdr`
+    pub fn header_size(&self) -> u16 {
+        match self {
+            Self::Elf32 => 52,
+            _ => 64,
+        }
+    }
+
+    /// Size of `Elf32_Phdr` or `Elf64_Phdr`
+    pub fn program_header_size(&self) -> u16 {
+        match self {
+            Self::Elf32 => 32,
+            _ => 56,
+        }
+    }
+
+    /// Size of `Elf32_Shdr` or `Elf64_Shdr`
+    pub fn section_header_size(&self) -> u16 {
+        match self {
+            Self::Elf32 => 40,
+            _ => 64,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+define_type!(
+    Data,
+    u8,
+    [
+        [None, 0, "Invalid data encoding", ELFDATANONE],
+        [Lsb, 1, "2's complement, little endian", ELFDATA2LSB],
+        [Msb, 2, "2's complement, big endian", ELFDATA2MSB],
+    ]
+);
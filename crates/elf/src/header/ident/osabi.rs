@@ -0,0 +1,31 @@
+define_type!(
+    OsAbi,
+    u8,
+    [
+        [SystemV, 0, "UNIX - System V", ELFOSABI_SYSV],
+        [HpUx, 1, "UNIX - HP-UX", ELFOSABI_HPUX],
+        [NetBsd, 2, "UNIX - NetBSD", ELFOSABI_NETBSD],
+        [Gnu, 3, "UNIX - GNU", ELFOSABI_GNU],
+        [Solaris, 6, "UNIX - Solaris", ELFOSABI_SOLARIS],
+        [Aix, 7, "UNIX - AIX", ELFOSABI_AIX],
+        [Irix, 8, "UNIX - IRIX", ELFOSABI_IRIX],
+        [FreeBsd, 9, "UNIX - FreeBSD", ELFOSABI_FREEBSD],
+        [Tru64, 10, "UNIX - TRU64", ELFOSABI_TRU64],
+        [Modesto, 11, "Novell - Modesto", ELFOSABI_MODESTO],
+        [OpenBsd, 12, "UNIX - OpenBSD", ELFOSABI_OPENBSD],
+        [OpenVms, 13, "VMS - OpenVMS", ELFOSABI_OPENVMS],
+        [Nsk, 14, "HP - Non-Stop Kernel", ELFOSABI_NSK],
+        [Aros, 15, "AROS", ELFOSABI_AROS],
+        [FenixOs, 16, "FenixOS", ELFOSABI_FENIXOS],
+        [CloudAbi, 17, "Nuxi CloudABI", ELFOSABI_CLOUDABI],
+        [
+            OpenVos,
+            18,
+            "Stratus Technologies OpenVOS",
+            ELFOSABI_OPENVOS
+        ],
+        [ArmAeabi, 64, "ARM EABI", ELFOSABI_ARM_AEABI],
+        [Arm, 97, "ARM", ELFOSABI_ARM],
+        [Standalone, 255, "Standalone App", ELFOSABI_STANDALONE],
+    ]
+);
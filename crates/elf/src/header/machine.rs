@@ -0,0 +1,26 @@
+define_type!(
+    Machine,
+    u16,
+    [
+        [None, 0, "No machine", EM_NONE],
+        [M32, 1, "AT&T WE 32100", EM_M32],
+        [Sparc, 2, "Sparc", EM_SPARC],
+        [I386, 3, "Intel 80386", EM_386],
+        [M68k, 4, "MC68000", EM_68K],
+        [M88k, 5, "MC88000", EM_88K],
+        [Mips, 8, "MIPS R3000", EM_MIPS],
+        [Parisc, 15, "HPPA", EM_PARISC],
+        [PowerPc, 20, "PowerPC", EM_PPC],
+        [PowerPc64, 21, "PowerPC64", EM_PPC64],
+        [S390, 22, "IBM S/390", EM_S390],
+        [Arm, 40, "ARM", EM_ARM],
+        [SuperH, 42, "Renesas / SuperH SH", EM_SH],
+        [SparcV9, 43, "Sparc v9", EM_SPARCV9],
+        [Ia64, 50, "Intel IA-64", EM_IA_64],
+        [X86_64, 62, "Advanced Micro Devices X86-64", EM_X86_64],
+        [AArch64, 183, "AArch64", EM_AARCH64],
+        [RiscV, 243, "RISC-V", EM_RISCV],
+        [Bpf, 247, "Linux BPF", EM_BPF],
+        [LoongArch, 258, "LoongArch", EM_LOONGARCH],
+    ]
+);
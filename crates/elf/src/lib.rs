@@ -0,0 +1,643 @@
+#![no_std]
+
+#[macro_use]
+pub mod macros;
+
+pub mod result;
+pub use result::*;
+
+pub mod cache;
+pub mod dynamic;
+pub mod hash;
+pub mod header;
+pub mod note;
+pub mod program;
+pub mod reader;
+pub mod relocation;
+pub mod scope;
+pub mod section;
+pub mod string;
+pub mod symbol;
+pub mod table;
+pub mod version;
+
+pub use header::Header;
+pub use reader::{Endianness, Reader};
+pub use table::Entry;
+
+/// How virtual addresses found inside an object translate to positions in its bytes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Layout {
+    /// The bytes are the file contents; addresses go through the `PT_LOAD` segments
+    File,
+    /// The bytes are a mapped image whose first byte sits at virtual address `base`
+    Mapped { base: u64 },
+}
+
+/// A parsed ELF object borrowing its raw bytes.
+#[derive(Debug, Copy, Clone)]
+pub struct Elf<'a> {
+    pub header: Header,
+    pub reader: Reader<'a>,
+    pub layout: Layout,
+}
+
+impl<'a> Elf<'a> {
+    /// Parse and validate the file header of an object held in memory
+    pub fn parse(bytes: &'a [u8]) -> Result<Self> {
+        let header = Header::parse(bytes)?;
+        let reader = header.ident.reader(bytes);
+
+        Ok(Self {
+            header,
+            reader,
+            layout: Layout::File,
+        })
+    }
+
+    /// Parse an object whose segments are already mapped, given the address of its ELF header
+    ///
+    /// # Safety
+    ///
+    /// `image` must point to a mapped ELF header, and every loadable segment of the object
+    /// must stay mapped at its address relative to that header for `'a`.
+    pub unsafe fn from_image(image: *const u8) -> Result<Self> {
+        let ident = unsafe { core::slice::from_raw_parts(image, header::ident::SIZE) };
+        let ident = header::Ident::parse(ident)?;
+
+        let size = ident.class.header_size() as usize;
+        let header = Header::parse(unsafe { core::slice::from_raw_parts(image, size) })?;
+
+        // Section headers are not mapped, so the extended count cannot be recovered
+        if header.phnum == program::PN_XNUM {
+            return Err(Error::MissingExtendedCount);
+        }
+
+        let size = header.phoff + header.phentsize as u64 * header.phnum as u64;
+        let headers = Self {
+            header,
+            reader: ident.reader(unsafe { core::slice::from_raw_parts(image, size as usize) }),
+            layout: Layout::Mapped { base: 0 },
+        };
+
+        // The ELF header is mapped by the segment that starts at file offset 0
+        let segments = headers.program_headers()?;
+        let first = segments
+            .loads()
+            .find(|segment| segment.offset == 0)
+            .ok_or(Error::UnmappedAddress)?;
+        let end = segments
+            .loads()
+            .map(|segment| segment.vaddr + segment.memsz)
+            .max()
+            .unwrap_or(first.vaddr);
+
+        let size = (end - first.vaddr) as usize;
+
+        Ok(Self {
+            header,
+            reader: ident.reader(unsafe { core::slice::from_raw_parts(image, size) }),
+            layout: Layout::Mapped { base: first.vaddr },
+        })
+    }
+
+    /// Parse the running program from the program header table the kernel announces to
+    /// its interpreter through `AT_PHDR` and `AT_PHNUM`.
+    ///
+    /// The table is read in the native class and byte order. Its `PT_PHDR` entry gives the
+    /// bias, without one the program is taken to sit at its linked addresses, and the
+    /// segment starting at file offset 0 holds the ELF header.
+    ///
+    /// # Safety
+    ///
+    /// `phdr` must point to `count` mapped program headers of an object mapped as
+    /// [`Elf::from_image`] requires.
+    pub unsafe fn from_program_headers(phdr: *const u8, count: usize) -> Result<Self> {
+        let class = match cfg!(target_pointer_width = "64") {
+            true => header::Class::Elf64,
+            false => header::Class::Elf32,
+        };
+        let endianness = match cfg!(target_endian = "little") {
+            true => Endianness::Little,
+            false => Endianness::Big,
+        };
+
+        let size = class.program_header_size() as u64;
+        let bytes = unsafe { core::slice::from_raw_parts(phdr, size as usize * count) };
+        let headers = program::Table::new(Reader::new(bytes, class, endianness), 0, size, count)?;
+
+        let bias = headers
+            .find(program::Type::PHdr)
+            .map_or(0, |header| (phdr as u64).wrapping_sub(header.vaddr));
+        let first = headers
+            .loads()
+            .find(|segment| segment.offset == 0)
+            .ok_or(Error::UnmappedAddress)?;
+
+        unsafe { Self::from_image(bias.wrapping_add(first.vaddr) as *const u8) }
+    }
+
+    pub fn bytes(&self) -> &'a [u8] {
+        self.reader.bytes
+    }
+
+    /// Number of program headers, following the `PN_XNUM` escape into section header 0
+    pub fn program_header_count(&self) -> Result<usize> {
+        match self.header.phnum {
+            program::PN_XNUM => Ok(self.initial_section()?.info as usize),
+            count => Ok(count as usize),
+        }
+    }
+
+    /// The program header table, empty when the object has none
+    pub fn program_headers(&self) -> Result<program::Table<'a>> {
+        if self.header.phoff == 0 || self.header.phnum == 0 {
+            return Ok(program::Table::empty(self.reader));
+        }
+
+        program::Table::new(
+            self.reader,
+            self.header.phoff,
+            self.header.phentsize as u64,
+            self.program_header_count()?,
+        )
+    }
+
+    /// Section header 0, which holds the real counts and indices of oversized objects
+    pub fn initial_section(&self) -> Result<section::Header> {
+        if self.header.shoff == 0 {
+            return Err(Error::MissingExtendedCount);
+        }
+
+        section::Header::parse(&self.reader, self.header.shoff)
+    }
+
+    /// Number of section headers, following the `e_shnum == 0` escape into section header 0
+    pub fn section_header_count(&self) -> Result<usize> {
+        // Section headers are not part of any loadable segment
+        if let Layout::Mapped { .. } = self.layout {
+            return Ok(0);
+        }
+
+        match (self.header.shoff, self.header.shnum) {
+            (0, _) => Ok(0),
+            (_, 0) => Ok(self.initial_section()?.size as usize),
+            (_, count) => Ok(count as usize),
+        }
+    }
+
+    /// The section header table, empty when the object has none
+    pub fn section_headers(&self) -> Result<section::Table<'a>> {
+        let count = self.section_header_count()?;
+
+        if count == 0 {
+            return Ok(section::Table::empty(self.reader));
+        }
+
+        section::Table::new(
+            self.reader,
+            self.header.shoff,
+            self.header.shentsize as u64,
+            count,
+        )
+    }
+
+    /// Index of the section name string table, following the `SHN_XINDEX` escape
+    pub fn section_names_index(&self) -> Result<usize> {
+        match self.header.shstrndx {
+            section::SHN_UNDEF => Err(Error::MissingStringTable),
+            section::SHN_XINDEX => Ok(self.initial_section()?.link as usize),
+            index => Ok(index as usize),
+        }
+    }
+
+    /// The section name string table
+    pub fn section_names(&self) -> Result<string::Table<'a>> {
+        let index = self.section_names_index()?;
+        let header = self.section(index)?;
+
+        if header.stype != section::Type::StrTab {
+            return Err(Error::MissingStringTable);
+        }
+
+        Ok(string::Table::new(header.data(&self.reader)?))
+    }
+
+    pub fn section(&self, index: usize) -> Result<section::Header> {
+        self.section_headers()?
+            .get(index)
+            .ok_or(Error::InvalidSectionIndex)
+    }
+
+    pub fn section_name(&self, header: &section::Header) -> Result<&'a str> {
+        self.section_names()?.get(header.name as u64)
+    }
+
+    /// Find a section such as `.dynsym` or `.note.gnu.build-id` by name
+    pub fn section_by_name(&self, name: &str) -> Result<section::Header> {
+        let names = self.section_names()?;
+
+        self.section_headers()?
+            .iter()
+            .find(|header| names.get(header.name as u64) == Ok(name))
+            .ok_or(Error::MissingSection)
+    }
+
+    pub fn section_data(&self, header: &section::Header) -> Result<&'a [u8]> {
+        header.data(&self.reader)
+    }
+
+    /// Difference between where the object is mapped and the addresses it was linked at
+    pub fn bias(&self) -> u64 {
+        match self.layout {
+            Layout::File => 0,
+            Layout::Mapped { base } => (self.reader.bytes.as_ptr() as u64).wrapping_sub(base),
+        }
+    }
+
+    /// Translate a virtual address of the object into an offset into its bytes
+    pub fn offset_of(&self, vaddr: u64) -> Result<u64> {
+        match self.layout {
+            Layout::File => self
+                .program_headers()?
+                .loads()
+                .find(|segment| vaddr >= segment.vaddr && vaddr - segment.vaddr < segment.filesz)
+                .map(|segment| segment.offset + (vaddr - segment.vaddr))
+                .ok_or(Error::UnmappedAddress),
+            Layout::Mapped { base } => vaddr.checked_sub(base).ok_or(Error::UnmappedAddress),
+        }
+    }
+
+    /// Bytes found at a virtual address of the object
+    pub fn slice_at(&self, vaddr: u64, size: u64) -> Result<&'a [u8]> {
+        self.reader.slice(self.offset_of(vaddr)?, size)
+    }
+
+    /// The `PT_DYNAMIC` array, with string-valued tags resolved through `DT_STRTAB`
+    pub fn dynamic(&self) -> Result<dynamic::Dynamic<'a>> {
+        let segment = self
+            .program_headers()?
+            .find(program::Type::Dynamic)
+            .ok_or(Error::MissingDynamic)?;
+
+        let (offset, size) = match self.layout {
+            Layout::File => (segment.offset, segment.filesz),
+            Layout::Mapped { .. } => (self.offset_of(segment.vaddr)?, segment.memsz),
+        };
+
+        let entry_size = 2 * self.reader.addr_size();
+        let table = dynamic::Table::new(
+            self.reader,
+            offset,
+            entry_size,
+            (size / entry_size) as usize,
+        )?;
+
+        let unresolved = dynamic::Dynamic::new(table, None);
+        let strings = match (
+            unresolved.get(dynamic::Tag::StrTab),
+            unresolved.get(dynamic::Tag::StrSz),
+        ) {
+            (Some(address), Some(size)) => Some(string::Table::new(self.slice_at(address, size)?)),
+            _ => None,
+        };
+
+        Ok(dynamic::Dynamic::new(table, strings))
+    }
+
+    /// The symbol table held by a `SHT_SYMTAB` or `SHT_DYNSYM` section, named through its linked section
+    pub fn symbols_in(&self, header: &section::Header) -> Result<symbol::Symbols<'a>> {
+        let strings = self.section(header.link as usize)?;
+        let strings = string::Table::new(strings.data(&self.reader)?);
+
+        let entry_size = symbol::entry_size(&self.reader);
+        let table = symbol::Table::new(
+            self.reader,
+            header.offset,
+            entry_size,
+            (header.size / entry_size) as usize,
+        )?;
+
+        Ok(symbol::Symbols::new(table, strings))
+    }
+
+    /// The static symbol table `.symtab`, absent from stripped objects
+    pub fn symbols(&self) -> Result<symbol::Symbols<'a>> {
+        let header = self
+            .section_headers()?
+            .find(section::Type::SymTab)
+            .ok_or(Error::MissingSymbolTable)?;
+
+        self.symbols_in(&header)
+    }
+
+    /// The dynamic symbol table, from `.dynsym` or else from `DT_SYMTAB`
+    pub fn dynamic_symbols(&self) -> Result<symbol::Symbols<'a>> {
+        if let Some(header) = self.section_headers()?.find(section::Type::DynSym) {
+            return self.symbols_in(&header);
+        }
+
+        let dynamic = self.dynamic()?;
+        let address = dynamic
+            .get(dynamic::Tag::SymTab)
+            .ok_or(Error::MissingSymbolTable)?;
+        let strings = dynamic.strings.ok_or(Error::MissingDynamicStrings)?;
+        let count = self.dynamic_symbol_count(&dynamic)?;
+
+        let table = symbol::Table::new(
+            self.reader,
+            self.offset_of(address)?,
+            symbol::entry_size(&self.reader),
+            count,
+        )?;
+
+        Ok(symbol::Symbols::new(table, strings))
+    }
+
+    /// Number of dynamic symbols when no section header records it: those the hash table
+    /// covers, and the symbols relocations refer to, as a GNU hash table leaves out the
+    /// undefined symbols linkers may place after `symoffset`
+    fn dynamic_symbol_count(&self, dynamic: &dynamic::Dynamic) -> Result<usize> {
+        let hashed = self.hash_table_of(dynamic)?.symbol_count()?;
+
+        let referenced = [self.dynamic_relocations()?, self.plt_relocations()?]
+            .into_iter()
+            .flatten()
+            .map(|relocation| relocation.symbol as usize + 1)
+            .max()
+            .unwrap_or(0);
+
+        Ok(hashed.max(referenced))
+    }
+
+    /// The symbol hash table, `DT_GNU_HASH` being preferred over `DT_HASH`
+    pub fn hash_table(&self) -> Result<hash::Hash<'a>> {
+        self.hash_table_of(&self.dynamic()?)
+    }
+
+    fn hash_table_of(&self, dynamic: &dynamic::Dynamic) -> Result<hash::Hash<'a>> {
+        if let Some(address) = dynamic.get(dynamic::Tag::GnuHash) {
+            let table = hash::gnu::Table::parse(self.reader, self.offset_of(address)?)?;
+            return Ok(hash::Hash::Gnu(table));
+        }
+
+        if let Some(address) = dynamic.get(dynamic::Tag::Hash) {
+            let table = hash::sysv::Table::parse(self.reader, self.offset_of(address)?)?;
+            return Ok(hash::Hash::Sysv(table));
+        }
+
+        Err(Error::MissingHashTable)
+    }
+
+    /// Symbol versions from `DT_VERSYM`, `DT_VERDEF` and `DT_VERNEED`, indexed like the dynamic symbols
+    pub fn versions(&self) -> Result<version::Versions<'a>> {
+        let dynamic = self.dynamic()?;
+        let address = dynamic
+            .get(dynamic::Tag::VerSym)
+            .ok_or(Error::MissingVersionTable)?;
+        let strings = dynamic.strings.ok_or(Error::MissingDynamicStrings)?;
+
+        let symbols = version::Table::new(
+            self.reader,
+            self.offset_of(address)?,
+            2,
+            self.dynamic_symbols()?.len(),
+        )?;
+
+        // Without a count the lists end at the first entry whose next offset is 0
+        let count = |tag| dynamic.get(tag).map_or(usize::MAX, |count| count as usize);
+
+        let definitions = match dynamic.get(dynamic::Tag::VerDef) {
+            Some(address) => Some(version::Definitions::new(
+                self.reader,
+                self.offset_of(address)?,
+                count(dynamic::Tag::VerDefNum),
+                strings,
+            )),
+            None => None,
+        };
+
+        let needs = match dynamic.get(dynamic::Tag::VerNeed) {
+            Some(address) => Some(version::Needs::new(
+                self.reader,
+                self.offset_of(address)?,
+                count(dynamic::Tag::VerNeedNum),
+                strings,
+            )),
+            None => None,
+        };
+
+        Ok(version::Versions::new(symbols, definitions, needs))
+    }
+
+    /// Find a defined dynamic symbol through the hash table, with its index
+    ///
+    /// The reference is either a plain name, bound to the default version, or
+    /// `name@VERSION` and `name@@VERSION`, which pick that version only.
+    pub fn lookup(&self, reference: &str) -> Result<Option<(usize, symbol::Symbol)>> {
+        let symbols = self.dynamic_symbols()?;
+        let hash = self.hash_table()?;
+        let (name, version, default) = version::split(reference);
+
+        let versions = match self.versions() {
+            Ok(versions) => versions,
+            Err(Error::MissingVersionTable) if version.is_none() => {
+                return Ok(hash.lookup(name, &symbols, |_, _| true));
+            }
+            Err(Error::MissingVersionTable) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        Ok(hash.lookup(name, &symbols, |index, _| {
+            versions.matches(index, version, default)
+        }))
+    }
+
+    /// Notes of a `PT_NOTE` segment
+    pub fn notes_in_segment(&self, header: &program::Header) -> Result<note::Notes<'a>> {
+        let offset = match self.layout {
+            Layout::File => header.offset,
+            Layout::Mapped { .. } => self.offset_of(header.vaddr)?,
+        };
+
+        note::Notes::new(self.reader, offset, header.filesz, header.align)
+    }
+
+    /// Notes of a `SHT_NOTE` section
+    pub fn notes_in_section(&self, header: &section::Header) -> Result<note::Notes<'a>> {
+        note::Notes::new(self.reader, header.offset, header.size, header.addralign)
+    }
+
+    /// Every note of the object, from its `PT_NOTE` segments or, when it has none, its `SHT_NOTE` sections
+    pub fn notes(&self) -> Result<impl Iterator<Item = note::Note<'a>> + 'a> {
+        let elf = *self;
+        let segments = self.program_headers()?;
+        let sections = self.section_headers()?;
+        let segmented = segments.find(program::Type::Note).is_some();
+
+        let in_segments = segments
+            .iter()
+            .filter(|header| header.ptype == program::Type::Note)
+            .flat_map(move |header| elf.notes_in_segment(&header).into_iter().flatten());
+        let in_sections = sections
+            .iter()
+            .filter(move |header| !segmented && header.stype == section::Type::Note)
+            .flat_map(move |header| elf.notes_in_section(&header).into_iter().flatten());
+
+        Ok(in_segments.chain(in_sections))
+    }
+
+    /// Descriptor of the `NT_GNU_BUILD_ID` note
+    pub fn build_id(&self) -> Result<Option<&'a [u8]>> {
+        Ok(self.notes()?.find_map(|note| note.build_id()))
+    }
+
+    /// CET features the object is marked compatible with, from its GNU property note
+    pub fn x86_features(&self) -> Result<Option<note::property::x86::Features>> {
+        Ok(self
+            .notes()?
+            .filter_map(|note| note.properties())
+            .flatten()
+            .find_map(|property| property.x86_features()))
+    }
+
+    /// Relocations of a `SHT_REL`, `SHT_RELA`, `SHT_RELR` or Android packed section
+    pub fn relocations_in(&self, header: &section::Header) -> Result<relocation::Relocations<'a>> {
+        let table = match header.stype {
+            section::Type::Rela | section::Type::Rel => {
+                let rela = header.stype == section::Type::Rela;
+                return self.relocations_at(header.offset, header.size, rela);
+            }
+            section::Type::Relr | section::Type::AndroidRelr => relocation::Table::Relr(
+                relocation::relr::Table::new(self.reader, header.offset, header.size)?,
+            ),
+            section::Type::AndroidRela | section::Type::AndroidRel => {
+                relocation::Table::Android(relocation::android::Table::new(
+                    self.reader,
+                    header.offset,
+                    header.size,
+                    header.stype == section::Type::AndroidRela,
+                )?)
+            }
+            _ => return Err(Error::MissingRelocations),
+        };
+
+        Ok(relocation::Relocations::new(table, self.header.machine))
+    }
+
+    /// Relocations the dynamic linker applies at load time, from `DT_RELA`, `DT_REL` or
+    /// their Android packed forms
+    pub fn dynamic_relocations(&self) -> Result<relocation::Relocations<'a>> {
+        let dynamic = self.dynamic()?;
+
+        for (address, size, rela) in [
+            (dynamic::Tag::Rela, dynamic::Tag::RelaSz, true),
+            (dynamic::Tag::Rel, dynamic::Tag::RelSz, false),
+        ] {
+            if let Some(address) = dynamic.get(address) {
+                let size = dynamic.get(size).ok_or(Error::MissingRelocations)?;
+                return self.relocations_at(self.offset_of(address)?, size, rela);
+            }
+        }
+
+        for (address, size, rela) in [
+            (dynamic::Tag::AndroidRela, dynamic::Tag::AndroidRelaSz, true),
+            (dynamic::Tag::AndroidRel, dynamic::Tag::AndroidRelSz, false),
+        ] {
+            if let Some(address) = dynamic.get(address) {
+                let size = dynamic.get(size).ok_or(Error::MissingRelocations)?;
+                let table = relocation::android::Table::new(
+                    self.reader,
+                    self.offset_of(address)?,
+                    size,
+                    rela,
+                )?;
+
+                return Ok(relocation::Relocations::new(
+                    relocation::Table::Android(table),
+                    self.header.machine,
+                ));
+            }
+        }
+
+        Ok(relocation::Relocations::empty(
+            self.reader,
+            self.header.machine,
+        ))
+    }
+
+    /// Relative relocations packed in `DT_RELR`, applied alongside the dynamic relocations
+    pub fn relative_relocations(&self) -> Result<relocation::Relocations<'a>> {
+        let dynamic = self.dynamic()?;
+
+        for (address, size) in [
+            (dynamic::Tag::Relr, dynamic::Tag::RelrSz),
+            (dynamic::Tag::AndroidRelr, dynamic::Tag::AndroidRelrSz),
+        ] {
+            if let Some(address) = dynamic.get(address) {
+                let size = dynamic.get(size).ok_or(Error::MissingRelocations)?;
+                let table =
+                    relocation::relr::Table::new(self.reader, self.offset_of(address)?, size)?;
+
+                return Ok(relocation::Relocations::new(
+                    relocation::Table::Relr(table),
+                    self.header.machine,
+                ));
+            }
+        }
+
+        Ok(relocation::Relocations::new(
+            relocation::Table::Relr(relocation::relr::Table::empty(self.reader)),
+            self.header.machine,
+        ))
+    }
+
+    /// Relocations of the PLT slots, which may be applied lazily, from `DT_JMPREL`
+    pub fn plt_relocations(&self) -> Result<relocation::Relocations<'a>> {
+        let dynamic = self.dynamic()?;
+
+        let Some(address) = dynamic.get(dynamic::Tag::JmpRel) else {
+            return Ok(relocation::Relocations::empty(
+                self.reader,
+                self.header.machine,
+            ));
+        };
+
+        let size = dynamic
+            .get(dynamic::Tag::PltRelSz)
+            .ok_or(Error::MissingRelocations)?;
+        let rela = match dynamic.get(dynamic::Tag::PltRel) {
+            Some(value) => value == dynamic::tag::DT_RELA as u64,
+            None => self.header.machine != header::Machine::I386,
+        };
+
+        self.relocations_at(self.offset_of(address)?, size, rela)
+    }
+
+    fn relocations_at(
+        &self,
+        offset: u64,
+        size: u64,
+        rela: bool,
+    ) -> Result<relocation::Relocations<'a>> {
+        let table = if rela {
+            let entry_size = relocation::rela_size(&self.reader);
+            relocation::Table::Rela(table::Table::new(
+                self.reader,
+                offset,
+                entry_size,
+                (size / entry_size) as usize,
+            )?)
+        } else {
+            let entry_size = relocation::rel_size(&self.reader);
+            relocation::Table::Rel(table::Table::new(
+                self.reader,
+                offset,
+                entry_size,
+                (size / entry_size) as usize,
+            )?)
+        };
+
+        Ok(relocation::Relocations::new(table, self.header.machine))
+    }
+}
@@ -0,0 +1,230 @@
+/// A macro that defines a typed view over a raw ELF field value.
+///
+/// This macro generates:
+/// 1. A constant for every known value, named after the ELF specification (e.g. `PT_LOAD`)
+/// 2. An enum with one variant per known value plus `Unknown` carrying anything else
+/// 3. `from`, `to`, `as_str` and `name` conversions between the raw value and the enum
+///
+/// # Arguments
+///
+/// * `$name` - The name of the enum
+/// * `$inner` - The raw integer type stored in the file
+/// * A list of known values with their description and specification constant name
+///   [VariantName, value, "description", SPEC_CONSTANT]
+///
+/// # Example
+///
+/// ```ignore
+/// define_type!(Type, u32, [
+///     [Null, 0, "Unused entry", PT_NULL],
+///     [Load, 1, "Loadable segment", PT_LOAD]
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! define_type {
+    ($name:ident, $inner:ty,
+     [ $( [$variant:ident, $value:expr, $description:expr, $constant:ident] ),* $(,)? ]) => {
+        $(
+            pub const $constant: $inner = $value;
+        )*
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        pub enum $name {
+            $($variant,)*
+            Unknown($inner),
+        }
+
+        impl $name {
+            pub fn from(value: $inner) -> Self {
+                match value {
+                    $($constant => Self::$variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+
+            pub fn to(self) -> $inner {
+                match self {
+                    $(Self::$variant => $constant,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)*
+                    Self::Unknown(_) => "Unknown",
+                }
+            }
+
+            /// Name of the value in the specification, e.g. `PT_LOAD`
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($constant),)*
+                    Self::Unknown(_) => "UNKNOWN",
+                }
+            }
+
+            pub fn is_known(&self) -> bool {
+                !matches!(self, Self::Unknown(_))
+            }
+        }
+    };
+}
+
+/// A macro that defines the error type of the elf crate.
+///
+/// This macro generates:
+/// 1. An Error enum with the specified variants and their associated error numbers
+/// 2. ErrorTrait implementation for the Error type
+/// 3. Conversion of the Error type into its error number
+///
+/// # Arguments
+///
+/// * `$error_enum_name` - The name of the error enum (usually Error)
+/// * `$fallback_variant` - The variant returned for unknown error numbers
+/// * A list of error variants with their error numbers and descriptions
+///   [VariantName, error_number, "description"]
+#[macro_export]
+macro_rules! define_error {
+    ($error_enum_name:ident, $fallback_variant:ident,
+     [ $( [$error_variant:ident, $errno:expr, $description:expr] ),* $(,)? ]) => {
+        use result::ErrorTrait;
+
+        #[repr(isize)]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        pub enum $error_enum_name {
+            $($error_variant = $errno,)*
+        }
+
+        impl ErrorTrait for $error_enum_name {
+            fn from_no(errno: isize) -> Self {
+                match errno {
+                    $($errno => Self::$error_variant,)*
+                    _ => Self::$fallback_variant,
+                }
+            }
+
+            fn describe(&self) -> &str {
+                match *self {
+                    $(Self::$error_variant => $description,)*
+                }
+            }
+
+            fn advert(&self) -> Option<isize> {
+                None
+            }
+        }
+
+        impl From<$error_enum_name> for isize {
+            fn from(error: $error_enum_name) -> isize {
+                error as isize
+            }
+        }
+    };
+}
+
+/// A macro that defines a single-bit flag enum and the bit set holding it.
+///
+/// This macro generates:
+/// 1. A constant for every flag, named after the ELF specification (e.g. `PF_X`)
+/// 2. A flag enum with one variant per bit
+/// 3. A set type wrapping the raw value, with `contains` and iteration over known flags
+/// 4. `|` between flags and sets producing a set
+///
+/// # Arguments
+///
+/// * `$set_name` - The name of the set type (usually Flags)
+/// * `$flag_name` - The name of the flag enum (usually Flag)
+/// * `$inner` - The raw integer type stored in the file
+/// * A list of flags with their description and specification constant name
+///   [VariantName, value, "description", SPEC_CONSTANT]
+#[macro_export]
+macro_rules! define_flags {
+    ($set_name:ident, $flag_name:ident, $inner:ident,
+     [ $( [$variant:ident, $value:expr, $description:expr, $constant:ident] ),* $(,)? ]) => {
+        $(
+            pub const $constant: $inner = $value;
+        )*
+
+        #[repr($inner)]
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        pub enum $flag_name {
+            $($variant = $value,)*
+        }
+
+        impl $flag_name {
+            pub const ALL: &'static [$flag_name] = &[$(Self::$variant,)*];
+
+            pub fn to(self) -> $inner {
+                self as $inner
+            }
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)*
+                }
+            }
+        }
+
+        #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+        pub struct $set_name(pub $inner);
+
+        impl $set_name {
+            pub fn from(value: $inner) -> Self {
+                Self(value)
+            }
+
+            pub fn to(self) -> $inner {
+                self.0
+            }
+
+            pub fn contains(&self, flag: $flag_name) -> bool {
+                self.0 & flag.to() == flag.to()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Iterate over the known flags present in the set
+            pub fn iter(&self) -> impl Iterator<Item = $flag_name> + '_ {
+                $flag_name::ALL.iter().copied().filter(|flag| self.contains(*flag))
+            }
+
+            /// Bits present in the set that no known flag accounts for
+            pub fn unknown(&self) -> $inner {
+                $flag_name::ALL.iter().fold(self.0, |bits, flag| bits & !flag.to())
+            }
+        }
+
+        impl From<$flag_name> for $set_name {
+            fn from(flag: $flag_name) -> Self {
+                Self(flag.to())
+            }
+        }
+
+        // Flag | Flag
+        impl core::ops::BitOr for $flag_name {
+            type Output = $set_name;
+            fn bitor(self, rhs: Self) -> $set_name {
+                $set_name(self.to() | rhs.to())
+            }
+        }
+
+        // Flags | Flag
+        impl core::ops::BitOr<$flag_name> for $set_name {
+            type Output = $set_name;
+            fn bitor(self, rhs: $flag_name) -> $set_name {
+                $set_name(self.0 | rhs.to())
+            }
+        }
+
+        // Flags | Flags
+        impl core::ops::BitOr for $set_name {
+            type Output = $set_name;
+            fn bitor(self, rhs: Self) -> $set_name {
+                $set_name(self.0 | rhs.0)
+            }
+        }
+    };
+}
@@ -0,0 +1,138 @@
+pub mod abi;
+pub mod ntype;
+pub mod property;
+
+pub use abi::{AbiTag, Os};
+pub use ntype::Type;
+pub use property::{Properties, Property};
+
+use crate::reader::Reader;
+use crate::result::{Error, Result};
+
+/// Owner of the notes the GNU toolchain emits
+pub const GNU: &[u8] = b"GNU";
+
+/// A single note: an owner name, a type meaningful within that owner, and a descriptor.
+#[derive(Debug, Copy, Clone)]
+pub struct Note<'a> {
+    pub name: &'a [u8], /* Owner name, without its terminating NUL */
+    pub ntype: u32,     /* Type of the descriptor, interpreted per owner */
+    pub desc: &'a [u8], /* Descriptor */
+    reader: Reader<'a>,
+    offset: u64,
+}
+
+impl<'a> Note<'a> {
+    pub fn is_gnu(&self) -> bool {
+        self.name == GNU
+    }
+
+    /// Type of a note owned by `GNU`
+    pub fn gnu_type(&self) -> Option<Type> {
+        self.is_gnu().then(|| Type::from(self.ntype))
+    }
+
+    /// Descriptor of an `NT_GNU_BUILD_ID` note
+    pub fn build_id(&self) -> Option<&'a [u8]> {
+        (self.gnu_type() == Some(Type::BuildId)).then_some(self.desc)
+    }
+
+    /// Decoded descriptor of an `NT_GNU_ABI_TAG` note
+    pub fn abi_tag(&self) -> Option<AbiTag> {
+        if self.gnu_type() != Some(Type::AbiTag) {
+            return None;
+        }
+
+        AbiTag::parse(&self.reader, self.offset, self.desc.len() as u64).ok()
+    }
+
+    /// Properties of an `NT_GNU_PROPERTY_TYPE_0` note
+    pub fn properties(&self) -> Option<Properties<'a>> {
+        if self.gnu_type() != Some(Type::PropertyType0) {
+            return None;
+        }
+
+        Some(Properties::new(
+            self.reader,
+            self.offset,
+            self.desc.len() as u64,
+        ))
+    }
+}
+
+/// Notes packed in a `PT_NOTE` segment or `SHT_NOTE` section.
+#[derive(Debug, Copy, Clone)]
+pub struct Notes<'a> {
+    reader: Reader<'a>,
+    offset: u64,
+    end: u64,
+    align: u64,
+}
+
+impl<'a> Notes<'a> {
+    /// Bind the notes held in `size` bytes at `offset`, padded to the alignment of their container
+    pub fn new(reader: Reader<'a>, offset: u64, size: u64, align: u64) -> Result<Self> {
+        reader.slice(offset, size)?;
+
+        Ok(Self {
+            reader,
+            offset,
+            end: offset + size,
+            // Only 8-byte aligned containers use 8-byte padding, anything else means 4
+            align: if align == 8 { 8 } else { 4 },
+        })
+    }
+
+    fn parse(&self) -> Result<(Note<'a>, u64)> {
+        let namesz = self.reader.u32(self.offset)? as u64;
+        let descsz = self.reader.u32(self.offset + 4)? as u64;
+        let ntype = self.reader.u32(self.offset + 8)?;
+
+        let name = self.offset + 12;
+        let desc = align(name + namesz, self.align);
+        let next = align(desc + descsz, self.align);
+
+        if desc + descsz > self.end {
+            return Err(Error::Truncated);
+        }
+
+        let name = self.reader.slice(name, namesz)?;
+        let name = name.strip_suffix(&[0]).unwrap_or(name);
+
+        let note = Note {
+            name,
+            ntype,
+            desc: self.reader.slice(desc, descsz)?,
+            reader: self.reader,
+            offset: desc,
+        };
+
+        Ok((note, next))
+    }
+}
+
+impl<'a> Iterator for Notes<'a> {
+    type Item = Note<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset + 12 > self.end {
+            return None;
+        }
+
+        match self.parse() {
+            Ok((note, next)) => {
+                self.offset = next;
+                Some(note)
+            }
+            Err(_) => {
+                self.offset = self.end;
+                None
+            }
+        }
+    }
+}
+
+/// Round `value` up to a multiple of the power of two `align`
+pub(crate) fn align(value: u64, align: u64) -> u64 {
+    (value + align - 1) & !(align - 1)
+}
@@ -0,0 +1,37 @@
+use crate::reader::Reader;
+use crate::result::{Error, Result};
+
+define_type!(
+    Os,
+    u32,
+    [
+        [Linux, 0, "Linux", ELF_NOTE_OS_LINUX],
+        [Gnu, 1, "GNU", ELF_NOTE_OS_GNU],
+        [Solaris2, 2, "Solaris", ELF_NOTE_OS_SOLARIS2],
+        [FreeBsd, 3, "FreeBSD", ELF_NOTE_OS_FREEBSD],
+    ]
+);
+
+/// The `NT_GNU_ABI_TAG` descriptor: the oldest kernel the object runs on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AbiTag {
+    pub os: Os,     /* Operating system */
+    pub major: u32, /* Earliest compatible kernel version */
+    pub minor: u32,
+    pub subminor: u32,
+}
+
+impl AbiTag {
+    pub fn parse(reader: &Reader, offset: u64, size: u64) -> Result<Self> {
+        if size < 16 {
+            return Err(Error::Truncated);
+        }
+
+        Ok(Self {
+            os: Os::from(reader.u32(offset)?),
+            major: reader.u32(offset + 4)?,
+            minor: reader.u32(offset + 8)?,
+            subminor: reader.u32(offset + 12)?,
+        })
+    }
+}
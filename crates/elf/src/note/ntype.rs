@@ -0,0 +1,16 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [AbiTag, 1, "ABI information", NT_GNU_ABI_TAG],
+        [Hwcap, 2, "Synthetic hardware capabilities", NT_GNU_HWCAP],
+        [BuildId, 3, "Unique build ID", NT_GNU_BUILD_ID],
+        [GoldVersion, 4, "Gold linker version", NT_GNU_GOLD_VERSION],
+        [
+            PropertyType0,
+            5,
+            "Program properties",
+            NT_GNU_PROPERTY_TYPE_0
+        ],
+    ]
+);
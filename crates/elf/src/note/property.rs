@@ -0,0 +1,88 @@
+pub mod aarch64;
+pub mod ptype;
+pub mod x86;
+
+pub use ptype::Type;
+
+use crate::reader::Reader;
+
+/// A single `NT_GNU_PROPERTY_TYPE_0` entry.
+#[derive(Debug, Copy, Clone)]
+pub struct Property<'a> {
+    pub ptype: Type,    /* Property type */
+    pub data: &'a [u8], /* Property descriptor */
+    reader: Reader<'a>,
+    offset: u64,
+}
+
+impl Property<'_> {
+    /// The 4-byte bitmask most properties carry
+    pub fn bits(&self) -> Option<u32> {
+        match self.data.len() {
+            4 => self.reader.u32(self.offset).ok(),
+            _ => None,
+        }
+    }
+
+    /// CET features every object of the program is compatible with, from `GNU_PROPERTY_X86_FEATURE_1_AND`
+    pub fn x86_features(&self) -> Option<x86::Features> {
+        match self.ptype {
+            Type::X86Feature1And => self.bits().map(x86::Features::from),
+            _ => None,
+        }
+    }
+
+    /// BTI and PAC marking from `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+    pub fn aarch64_features(&self) -> Option<aarch64::Features> {
+        match self.ptype {
+            Type::AArch64Feature1And => self.bits().map(aarch64::Features::from),
+            _ => None,
+        }
+    }
+}
+
+/// Entries of a property note, each padded to the address size of the object.
+#[derive(Debug, Copy, Clone)]
+pub struct Properties<'a> {
+    reader: Reader<'a>,
+    offset: u64,
+    end: u64,
+}
+
+impl<'a> Properties<'a> {
+    pub fn new(reader: Reader<'a>, offset: u64, size: u64) -> Self {
+        Self {
+            reader,
+            offset,
+            end: offset + size,
+        }
+    }
+}
+
+impl<'a> Iterator for Properties<'a> {
+    type Item = Property<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset + 8 > self.end {
+            return None;
+        }
+
+        let ptype = self.reader.u32(self.offset).ok()?;
+        let size = self.reader.u32(self.offset + 4).ok()? as u64;
+        let offset = self.offset + 8;
+
+        if offset + size > self.end {
+            self.offset = self.end;
+            return None;
+        }
+
+        self.offset = super::align(offset + size, self.reader.addr_size());
+
+        Some(Property {
+            ptype: Type::from(ptype),
+            data: self.reader.slice(offset, size).ok()?,
+            reader: self.reader,
+            offset,
+        })
+    }
+}
@@ -0,0 +1,19 @@
+define_flags!(
+    Features,
+    Feature,
+    u32,
+    [
+        [
+            Bti,
+            0x1,
+            "Branch target identification",
+            GNU_PROPERTY_AARCH64_FEATURE_1_BTI
+        ],
+        [
+            Pac,
+            0x2,
+            "Pointer authentication",
+            GNU_PROPERTY_AARCH64_FEATURE_1_PAC
+        ],
+    ]
+);
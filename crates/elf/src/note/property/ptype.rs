@@ -0,0 +1,55 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [StackSize, 1, "Stack size", GNU_PROPERTY_STACK_SIZE],
+        [
+            NoCopyOnProtected,
+            2,
+            "No copy relocation on protected data symbol",
+            GNU_PROPERTY_NO_COPY_ON_PROTECTED
+        ],
+        [
+            Needed1,
+            0xb0008000,
+            "Features the program requires",
+            GNU_PROPERTY_1_NEEDED
+        ],
+        [
+            AArch64Feature1And,
+            0xc0000000,
+            "AArch64 BTI and PAC compatibility",
+            GNU_PROPERTY_AARCH64_FEATURE_1_AND
+        ],
+        [
+            X86Feature1And,
+            0xc0000002,
+            "x86 CET compatibility",
+            GNU_PROPERTY_X86_FEATURE_1_AND
+        ],
+        [
+            X86Isa1Needed,
+            0xc0008002,
+            "x86 ISA level needed",
+            GNU_PROPERTY_X86_ISA_1_NEEDED
+        ],
+        [
+            X86Feature2Needed,
+            0xc0008001,
+            "x86 features needed",
+            GNU_PROPERTY_X86_FEATURE_2_NEEDED
+        ],
+        [
+            X86Isa1Used,
+            0xc0010002,
+            "x86 ISA level used",
+            GNU_PROPERTY_X86_ISA_1_USED
+        ],
+        [
+            X86Feature2Used,
+            0xc0010001,
+            "x86 features used",
+            GNU_PROPERTY_X86_FEATURE_2_USED
+        ],
+    ]
+);
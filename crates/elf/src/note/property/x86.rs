@@ -0,0 +1,33 @@
+define_flags!(
+    Features,
+    Feature,
+    u32,
+    [
+        [
+            Ibt,
+            0x1,
+            "Indirect branch tracking",
+            GNU_PROPERTY_X86_FEATURE_1_IBT
+        ],
+        [Shstk, 0x2, "Shadow stack", GNU_PROPERTY_X86_FEATURE_1_SHSTK],
+        [
+            LamU48,
+            0x4,
+            "Linear address masking, 48-bit",
+            GNU_PROPERTY_X86_FEATURE_1_LAM_U48
+        ],
+        [
+            LamU57,
+            0x8,
+            "Linear address masking, 57-bit",
+            GNU_PROPERTY_X86_FEATURE_1_LAM_U57
+        ],
+    ]
+);
+
+impl Features {
+    /// Whether the program is marked for indirect branch tracking or shadow stacks
+    pub fn is_cet(&self) -> bool {
+        self.contains(Feature::Ibt) || self.contains(Feature::Shstk)
+    }
+}
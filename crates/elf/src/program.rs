@@ -0,0 +1,21 @@
+pub mod header;
+
+pub use header::{Flag, Flags, Header, Type};
+
+/// `e_phnum` value meaning the real count lives in `sh_info` of section header 0
+pub const PN_XNUM: u16 = 0xffff;
+
+/// The program header table
+pub type Table<'a> = crate::table::Table<'a, Header>;
+
+impl<'a> Table<'a> {
+    /// First segment of the given type
+    pub fn find(&self, ptype: Type) -> Option<Header> {
+        self.iter().find(|header| header.ptype == ptype)
+    }
+
+    /// Loadable segments, in table order
+    pub fn loads(&self) -> impl Iterator<Item = Header> + 'a {
+        self.iter().filter(Header::is_load)
+    }
+}
@@ -0,0 +1,67 @@
+pub mod flag;
+pub mod ptype;
+
+pub use flag::{Flag, Flags};
+pub use ptype::Type;
+
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::table::Entry;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Header {
+    pub ptype: Type,  /* Type of segment */
+    pub flags: Flags, /* Segment attributes */
+    pub offset: u64,  /* Offset in file */
+    pub vaddr: u64,   /* Virtual address in memory */
+    pub paddr: u64,   /* Reserved */
+    pub filesz: u64,  /* Size of segment in file */
+    pub memsz: u64,   /* Size of segment in memory */
+    pub align: u64,   /* Alignment of segment */
+}
+
+impl Entry for Header {
+    /// Decode the `Elf32_Phdr` or `Elf64_Phdr` found at `offset`
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        // The 32-bit layout keeps `p_flags` after the sizes instead of after `p_type`
+        if reader.is_32() {
+            return Ok(Self {
+                ptype: Type::from(reader.u32(offset)?),
+                offset: reader.u32(offset + 4)? as u64,
+                vaddr: reader.u32(offset + 8)? as u64,
+                paddr: reader.u32(offset + 12)? as u64,
+                filesz: reader.u32(offset + 16)? as u64,
+                memsz: reader.u32(offset + 20)? as u64,
+                flags: Flags::from(reader.u32(offset + 24)?),
+                align: reader.u32(offset + 28)? as u64,
+            });
+        }
+
+        Ok(Self {
+            ptype: Type::from(reader.u32(offset)?),
+            flags: Flags::from(reader.u32(offset + 4)?),
+            offset: reader.u64(offset + 8)?,
+            vaddr: reader.u64(offset + 16)?,
+            paddr: reader.u64(offset + 24)?,
+            filesz: reader.u64(offset + 32)?,
+            memsz: reader.u64(offset + 40)?,
+            align: reader.u64(offset + 48)?,
+        })
+    }
+}
+
+impl Header {
+    pub fn is_load(&self) -> bool {
+        self.ptype == Type::Load
+    }
+
+    /// Bytes of the segment as stored in the file
+    pub fn data<'a>(&self, reader: &Reader<'a>) -> Result<&'a [u8]> {
+        reader.slice(self.offset, self.filesz)
+    }
+
+    /// Whether the virtual address falls inside the memory image of the segment
+    pub fn contains_vaddr(&self, vaddr: u64) -> bool {
+        vaddr >= self.vaddr && vaddr - self.vaddr < self.memsz
+    }
+}
@@ -0,0 +1,27 @@
+define_flags!(
+    Flags,
+    Flag,
+    u32,
+    [
+        [X, 0x1, "Execute permission", PF_X],
+        [W, 0x2, "Write permission", PF_W],
+        [R, 0x4, "Read permission", PF_R],
+    ]
+);
+
+pub const PF_MASKOS: u32 = 0x0ff00000;
+pub const PF_MASKPROC: u32 = 0xf0000000;
+
+impl Flags {
+    pub fn is_readable(&self) -> bool {
+        self.contains(Flag::R)
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.contains(Flag::W)
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.contains(Flag::X)
+    }
+}
@@ -0,0 +1,48 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [Null, 0, "Unused entry", PT_NULL],
+        [Load, 1, "Loadable segment", PT_LOAD],
+        [Dynamic, 2, "Dynamic linking tables", PT_DYNAMIC],
+        [Interp, 3, "Program interpreter path name", PT_INTERP],
+        [Note, 4, "Note sections", PT_NOTE],
+        [Shlib, 5, "Reserved", PT_SHLIB],
+        [PHdr, 6, "Program header table", PT_PHDR],
+        [Tls, 7, "Thread-local storage template", PT_TLS],
+        [
+            GnuEhFrame,
+            0x6474e550,
+            "GCC .eh_frame_hdr segment",
+            PT_GNU_EH_FRAME
+        ],
+        [GnuStack, 0x6474e551, "Stack executability", PT_GNU_STACK],
+        [
+            GnuRelro,
+            0x6474e552,
+            "Read-only after relocation",
+            PT_GNU_RELRO
+        ],
+        [
+            GnuProperty,
+            0x6474e553,
+            "GNU property notes",
+            PT_GNU_PROPERTY
+        ],
+    ]
+);
+
+pub const PT_LOOS: u32 = 0x60000000;
+pub const PT_HIOS: u32 = 0x6fffffff;
+pub const PT_LOPROC: u32 = 0x70000000;
+pub const PT_HIPROC: u32 = 0x7fffffff;
+
+impl Type {
+    pub fn is_os_specific(&self) -> bool {
+        (PT_LOOS..=PT_HIOS).contains(&self.to())
+    }
+
+    pub fn is_processor_specific(&self) -> bool {
+        (PT_LOPROC..=PT_HIPROC).contains(&self.to())
+    }
+}
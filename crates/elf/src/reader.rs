@@ -0,0 +1,86 @@
+use crate::header::Class;
+use crate::result::{Error, Result};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+/// Bounds-checked access to the raw bytes of an object in its own class and byte order.
+#[derive(Debug, Copy, Clone)]
+pub struct Reader<'a> {
+    pub bytes: &'a [u8],
+    pub class: Class,
+    pub endianness: Endianness,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8], class: Class, endianness: Endianness) -> Self {
+        Self {
+            bytes,
+            class,
+            endianness,
+        }
+    }
+
+    pub fn is_32(&self) -> bool {
+        self.class == Class::Elf32
+    }
+
+    /// Get `length` bytes starting at `offset`
+    pub fn slice(&self, offset: u64, length: u64) -> Result<&'a [u8]> {
+        let start = usize::try_from(offset).map_err(|_| Error::Truncated)?;
+        let length = usize::try_from(length).map_err(|_| Error::Truncated)?;
+        let end = start.checked_add(length).ok_or(Error::Truncated)?;
+
+        self.bytes.get(start..end).ok_or(Error::Truncated)
+    }
+
+    fn array<const N: usize>(&self, offset: u64) -> Result<[u8; N]> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.slice(offset, N as u64)?);
+        Ok(array)
+    }
+
+    pub fn u8(&self, offset: u64) -> Result<u8> {
+        Ok(self.array::<1>(offset)?[0])
+    }
+
+    pub fn u16(&self, offset: u64) -> Result<u16> {
+        let bytes = self.array(offset)?;
+        Ok(match self.endianness {
+            Endianness::Little => u16::from_le_bytes(bytes),
+            Endianness::Big => u16::from_be_bytes(bytes),
+        })
+    }
+
+    pub fn u32(&self, offset: u64) -> Result<u32> {
+        let bytes = self.array(offset)?;
+        Ok(match self.endianness {
+            Endianness::Little => u32::from_le_bytes(bytes),
+            Endianness::Big => u32::from_be_bytes(bytes),
+        })
+    }
+
+    pub fn u64(&self, offset: u64) -> Result<u64> {
+        let bytes = self.array(offset)?;
+        Ok(match self.endianness {
+            Endianness::Little => u64::from_le_bytes(bytes),
+            Endianness::Big => u64::from_be_bytes(bytes),
+        })
+    }
+
+    /// Read a class-sized field such as `Elf32_Addr` or `Elf64_Xword`, widened to 64 bits
+    pub fn addr(&self, offset: u64) -> Result<u64> {
+        match self.class {
+            Class::Elf32 => Ok(self.u32(offset)? as u64),
+            _ => self.u64(offset),
+        }
+    }
+
+    /// Size in bytes of the fields read by `addr`
+    pub fn addr_size(&self) -> u64 {
+        self.class.addr_size()
+    }
+}
@@ -0,0 +1,234 @@
+pub mod aarch64;
+pub mod android;
+pub mod kind;
+pub mod relr;
+pub mod riscv;
+pub mod x86_64;
+
+pub use kind::Kind;
+
+use crate::header::Machine;
+use crate::reader::Reader;
+use crate::result::Result;
+
+/// An `Elf32_Rel` or `Elf64_Rel` entry, whose addend is stored at the relocated place.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Rel {
+    pub offset: u64, /* Address */
+    pub info: u64,   /* Relocation type and symbol index */
+}
+
+impl crate::table::Entry for Rel {
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        Ok(Self {
+            offset: reader.addr(offset)?,
+            info: reader.addr(offset + reader.addr_size())?,
+        })
+    }
+}
+
+/// An `Elf32_Rela` or `Elf64_Rela` entry, carrying an explicit addend.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Rela {
+    pub offset: u64, /* Address */
+    pub info: u64,   /* Relocation type and symbol index */
+    pub addend: i64, /* Addend */
+}
+
+impl crate::table::Entry for Rela {
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        let addend = reader.addr(offset + 2 * reader.addr_size())?;
+
+        Ok(Self {
+            offset: reader.addr(offset)?,
+            info: reader.addr(offset + reader.addr_size())?,
+            addend: match reader.is_32() {
+                true => addend as u32 as i32 as i64,
+                false => addend as i64,
+            },
+        })
+    }
+}
+
+/// Size of a `Rel` entry for the class of the object
+pub fn rel_size(reader: &Reader) -> u64 {
+    2 * reader.addr_size()
+}
+
+/// Size of a `Rela` entry for the class of the object
+pub fn rela_size(reader: &Reader) -> u64 {
+    3 * reader.addr_size()
+}
+
+/// A relocation with `r_info` split into its symbol index and machine-specific type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Relocation {
+    pub offset: u64,         /* Address of the place to relocate */
+    pub symbol: u32,         /* Index into the linked symbol table, 0 for none */
+    pub kind: Kind,          /* Relocation type */
+    pub addend: Option<i64>, /* Explicit addend of RELA entries */
+}
+
+impl Relocation {
+    fn new(reader: &Reader, machine: Machine, offset: u64, info: u64, addend: Option<i64>) -> Self {
+        let (symbol, rtype) = match reader.is_32() {
+            true => ((info >> 8) as u32, (info & 0xff) as u32),
+            false => ((info >> 32) as u32, info as u32),
+        };
+
+        Self {
+            offset,
+            symbol,
+            kind: Kind::from(machine, rtype),
+            addend,
+        }
+    }
+}
+
+/// A relocation table in any of its formats.
+#[derive(Debug, Copy, Clone)]
+pub enum Table<'a> {
+    Rel(crate::table::Table<'a, Rel>),
+    Rela(crate::table::Table<'a, Rela>),
+    Relr(relr::Table<'a>),
+    Android(android::Table<'a>),
+}
+
+/// A relocation table together with the machine its types belong to.
+#[derive(Debug, Copy, Clone)]
+pub struct Relocations<'a> {
+    pub table: Table<'a>,
+    pub machine: Machine,
+}
+
+impl<'a> Relocations<'a> {
+    pub fn new(table: Table<'a>, machine: Machine) -> Self {
+        Self { table, machine }
+    }
+
+    pub fn empty(reader: Reader<'a>, machine: Machine) -> Self {
+        Self::new(Table::Rela(crate::table::Table::empty(reader)), machine)
+    }
+
+    /// Number of relocations, which takes a full decoding pass for RELR tables
+    pub fn len(&self) -> usize {
+        match self.table {
+            Table::Rel(table) => table.len(),
+            Table::Rela(table) => table.len(),
+            Table::Relr(table) => table.iter().count(),
+            Table::Android(table) => table.count,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn has_addends(&self) -> bool {
+        match self.table {
+            Table::Rela(_) => true,
+            Table::Android(table) => table.rela,
+            Table::Rel(_) | Table::Relr(_) => false,
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<Relocation> {
+        self.iter().nth(index)
+    }
+
+    /// Relocations in table order, packed formats unfolded into one entry per place
+    pub fn iter(&self) -> Iter<'a> {
+        let source = match self.table {
+            Table::Rel(_) | Table::Rela(_) => Source::Indexed(0),
+            Table::Relr(table) => Source::Relr(table.iter()),
+            Table::Android(table) => Source::Android(table.iter()),
+        };
+
+        Iter {
+            relocations: *self,
+            source,
+        }
+    }
+
+    /// Relocations that only add the load bias, applicable before any symbol is known
+    pub fn relative(&self) -> impl Iterator<Item = Relocation> + 'a {
+        self.iter()
+            .filter(|relocation| relocation.kind.is_relative())
+    }
+}
+
+impl<'a> IntoIterator for Relocations<'a> {
+    type Item = Relocation;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Source<'a> {
+    Indexed(usize),
+    Relr(relr::Iter<'a>),
+    Android(android::Iter<'a>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    relocations: Relocations<'a>,
+    source: Source<'a>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Relocation;
+
+    fn next(&mut self) -> Option<Relocation> {
+        let machine = self.relocations.machine;
+
+        match (&mut self.source, self.relocations.table) {
+            (Source::Indexed(index), Table::Rel(table)) => {
+                let entry = table.get(*index)?;
+                *index += 1;
+                let reader = table.reader();
+                Some(Relocation::new(
+                    &reader,
+                    machine,
+                    entry.offset,
+                    entry.info,
+                    None,
+                ))
+            }
+            (Source::Indexed(index), Table::Rela(table)) => {
+                let entry = table.get(*index)?;
+                *index += 1;
+                let reader = table.reader();
+                let addend = Some(entry.addend);
+                Some(Relocation::new(
+                    &reader,
+                    machine,
+                    entry.offset,
+                    entry.info,
+                    addend,
+                ))
+            }
+            (Source::Relr(iter), _) => iter.next().map(|offset| Relocation {
+                offset,
+                symbol: 0,
+                kind: Kind::relative(machine),
+                addend: None,
+            }),
+            (Source::Android(iter), Table::Android(table)) => {
+                let (offset, info, addend) = iter.next()?;
+                let addend = table.rela.then_some(addend);
+                Some(Relocation::new(
+                    &table.reader(),
+                    machine,
+                    offset,
+                    info,
+                    addend,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
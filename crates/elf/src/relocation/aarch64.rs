@@ -0,0 +1,73 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [None, 0, "No relocation", R_AARCH64_NONE],
+        [Abs64, 257, "Direct 64 bit", R_AARCH64_ABS64],
+        [Abs32, 258, "Direct 32 bit", R_AARCH64_ABS32],
+        [Abs16, 259, "Direct 16-bit", R_AARCH64_ABS16],
+        [Prel64, 260, "PC-relative 64-bit", R_AARCH64_PREL64],
+        [Prel32, 261, "PC-relative 32-bit", R_AARCH64_PREL32],
+        [Prel16, 262, "PC-relative 16-bit", R_AARCH64_PREL16],
+        [
+            AdrPrelPgHi21,
+            275,
+            "Page-rel. ADRP imm. from 32:12",
+            R_AARCH64_ADR_PREL_PG_HI21
+        ],
+        [
+            AddAbsLo12Nc,
+            277,
+            "Dir. ADD imm. from bits 11:0",
+            R_AARCH64_ADD_ABS_LO12_NC
+        ],
+        [
+            Jump26,
+            282,
+            "PC-rel. B imm. from bits 27:2",
+            R_AARCH64_JUMP26
+        ],
+        [Call26, 283, "Likewise for CALL", R_AARCH64_CALL26],
+        [
+            AdrGotPage,
+            311,
+            "P-page-rel. GOT off. ADRP 32:12",
+            R_AARCH64_ADR_GOT_PAGE
+        ],
+        [
+            Ld64GotLo12Nc,
+            312,
+            "Dir. GOT off. LD/ST imm. 11:3",
+            R_AARCH64_LD64_GOT_LO12_NC
+        ],
+        [Copy, 1024, "Copy symbol at runtime", R_AARCH64_COPY],
+        [GlobDat, 1025, "Create GOT entry", R_AARCH64_GLOB_DAT],
+        [JumpSlot, 1026, "Create PLT entry", R_AARCH64_JUMP_SLOT],
+        [Relative, 1027, "Adjust by program base", R_AARCH64_RELATIVE],
+        [
+            TlsDtpMod,
+            1028,
+            "Module number, 64 bit",
+            R_AARCH64_TLS_DTPMOD
+        ],
+        [
+            TlsDtpRel,
+            1029,
+            "Module-relative offset, 64 bit",
+            R_AARCH64_TLS_DTPREL
+        ],
+        [
+            TlsTpRel,
+            1030,
+            "TP-relative offset, 64 bit",
+            R_AARCH64_TLS_TPREL
+        ],
+        [TlsDesc, 1031, "TLS Descriptor", R_AARCH64_TLSDESC],
+        [
+            IRelative,
+            1032,
+            "STT_GNU_IFUNC relocation",
+            R_AARCH64_IRELATIVE
+        ],
+    ]
+);
@@ -0,0 +1,190 @@
+use crate::reader::Reader;
+use crate::result::{Error, Result};
+
+/// Magic opening an Android packed relocation table
+pub const MAGIC: &[u8; 4] = b"APS2";
+
+pub const RELOCATION_GROUPED_BY_INFO_FLAG: u64 = 1;
+pub const RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG: u64 = 2;
+pub const RELOCATION_GROUPED_BY_ADDEND_FLAG: u64 = 4;
+pub const RELOCATION_GROUP_HAS_ADDEND_FLAG: u64 = 8;
+
+/// A `DT_ANDROID_REL` or `DT_ANDROID_RELA` table: groups of relocations sharing fields,
+/// delta-encoded as SLEB128 numbers.
+#[derive(Debug, Copy, Clone)]
+pub struct Table<'a> {
+    reader: Reader<'a>,
+    offset: u64,
+    end: u64,
+    pub count: usize,
+    pub rela: bool,
+}
+
+impl<'a> Table<'a> {
+    /// Bind the `size` bytes of packed relocations at `offset`, checking the `APS2` magic
+    pub fn new(reader: Reader<'a>, offset: u64, size: u64, rela: bool) -> Result<Self> {
+        if reader.slice(offset, size)?.get(..4) != Some(&MAGIC[..]) {
+            return Err(Error::Malformed);
+        }
+
+        let mut position = offset + 4;
+        let count = sleb128(&reader, &mut position)?;
+
+        Ok(Self {
+            reader,
+            offset,
+            end: offset + size,
+            count: count as usize,
+            rela,
+        })
+    }
+
+    pub fn reader(&self) -> Reader<'a> {
+        self.reader
+    }
+
+    /// Relocations as `(r_offset, r_info, r_addend)`, the addend being 0 in REL tables
+    pub fn iter(&self) -> Iter<'a> {
+        let mut position = self.offset + 4;
+
+        // The relocation count and the offset the first delta applies to
+        let count = sleb128(&self.reader, &mut position).unwrap_or(0);
+        let offset = sleb128(&self.reader, &mut position).unwrap_or(0);
+
+        Iter {
+            table: *self,
+            position,
+            remaining: count as usize,
+            group: 0,
+            flags: 0,
+            offset_delta: 0,
+            offset: offset as u64,
+            info: 0,
+            addend: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    table: Table<'a>,
+    position: u64,
+    remaining: usize,
+    group: u64, /* Relocations left in the current group */
+    flags: u64, /* RELOCATION_* flags of the current group */
+    offset_delta: u64,
+    offset: u64,
+    info: u64,
+    addend: i64,
+}
+
+impl Iter<'_> {
+    fn read(&mut self) -> Result<i64> {
+        if self.position >= self.table.end {
+            return Err(Error::Truncated);
+        }
+
+        sleb128(&self.table.reader, &mut self.position)
+    }
+
+    fn has(&self, flag: u64) -> bool {
+        self.flags & flag != 0
+    }
+
+    fn start_group(&mut self) -> Result<()> {
+        self.group = self.read()? as u64;
+        self.flags = self.read()? as u64;
+
+        if self.has(RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG) {
+            self.offset_delta = self.read()? as u64;
+        }
+
+        if self.has(RELOCATION_GROUPED_BY_INFO_FLAG) {
+            self.info = self.read()? as u64;
+        }
+
+        if !self.has(RELOCATION_GROUP_HAS_ADDEND_FLAG) {
+            self.addend = 0;
+        } else if self.has(RELOCATION_GROUPED_BY_ADDEND_FLAG) {
+            if !self.table.rela {
+                return Err(Error::Malformed);
+            }
+            self.addend = self.addend.wrapping_add(self.read()?);
+        }
+
+        Ok(())
+    }
+
+    fn relocation(&mut self) -> Result<(u64, u64, i64)> {
+        while self.group == 0 {
+            self.start_group()?;
+        }
+
+        let delta = match self.has(RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG) {
+            true => self.offset_delta,
+            false => self.read()? as u64,
+        };
+        self.offset = self.offset.wrapping_add(delta);
+
+        if !self.has(RELOCATION_GROUPED_BY_INFO_FLAG) {
+            self.info = self.read()? as u64;
+        }
+
+        if self.has(RELOCATION_GROUP_HAS_ADDEND_FLAG)
+            && !self.has(RELOCATION_GROUPED_BY_ADDEND_FLAG)
+        {
+            if !self.table.rela {
+                return Err(Error::Malformed);
+            }
+            self.addend = self.addend.wrapping_add(self.read()?);
+        }
+
+        self.group -= 1;
+
+        Ok((self.offset, self.info, self.addend))
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = (u64, u64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        match self.relocation() {
+            Ok(relocation) => {
+                self.remaining -= 1;
+                Some(relocation)
+            }
+            Err(_) => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+}
+
+/// Decode the signed LEB128 number at `position`, moving it past the number
+pub fn sleb128(reader: &Reader, position: &mut u64) -> Result<i64> {
+    let mut value: i64 = 0;
+    let mut shift = 0;
+
+    loop {
+        let byte = reader.u8(*position)?;
+        *position += 1;
+
+        if shift < 64 {
+            value |= ((byte & 0x7f) as i64) << shift;
+        }
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            if shift < 64 && byte & 0x40 != 0 {
+                value |= -1i64 << shift;
+            }
+            return Ok(value);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use super::{aarch64, riscv, x86_64};
+use crate::header::Machine;
+
+/// A relocation type, interpreted according to the machine of the object.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Kind {
+    X86_64(x86_64::Type),
+    AArch64(aarch64::Type),
+    RiscV(riscv::Type),
+    Unknown(Machine, u32), /* Type of a machine without a decoder */
+}
+
+impl Kind {
+    pub fn from(machine: Machine, value: u32) -> Self {
+        match machine {
+            Machine::X86_64 => Self::X86_64(x86_64::Type::from(value)),
+            Machine::AArch64 => Self::AArch64(aarch64::Type::from(value)),
+            Machine::RiscV => Self::RiscV(riscv::Type::from(value)),
+            machine => Self::Unknown(machine, value),
+        }
+    }
+
+    /// The `R_*_RELATIVE` type of a machine, which packed relative relocations stand for
+    pub fn relative(machine: Machine) -> Self {
+        match machine {
+            Machine::X86_64 => Self::X86_64(x86_64::Type::Relative),
+            Machine::AArch64 => Self::AArch64(aarch64::Type::Relative),
+            Machine::RiscV => Self::RiscV(riscv::Type::Relative),
+            machine => Self::Unknown(machine, 0),
+        }
+    }
+
+    pub fn to(self) -> u32 {
+        match self {
+            Self::X86_64(rtype) => rtype.to(),
+            Self::AArch64(rtype) => rtype.to(),
+            Self::RiscV(rtype) => rtype.to(),
+            Self::Unknown(_, value) => value,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::X86_64(rtype) => rtype.as_str(),
+            Self::AArch64(rtype) => rtype.as_str(),
+            Self::RiscV(rtype) => rtype.as_str(),
+            Self::Unknown(..) => "Unknown",
+        }
+    }
+
+    /// Name of the type in the psABI, e.g. `R_X86_64_RELATIVE`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::X86_64(rtype) => rtype.name(),
+            Self::AArch64(rtype) => rtype.name(),
+            Self::RiscV(rtype) => rtype.name(),
+            Self::Unknown(..) => "UNKNOWN",
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        matches!(
+            self,
+            Self::X86_64(x86_64::Type::None)
+                | Self::AArch64(aarch64::Type::None)
+                | Self::RiscV(riscv::Type::None)
+        )
+    }
+
+    /// Base address plus addend, independent of any symbol
+    pub fn is_relative(&self) -> bool {
+        matches!(
+            self,
+            Self::X86_64(x86_64::Type::Relative)
+                | Self::AArch64(aarch64::Type::Relative)
+                | Self::RiscV(riscv::Type::Relative)
+        )
+    }
+
+    /// Address returned by the resolver function found at base address plus addend
+    pub fn is_irelative(&self) -> bool {
+        matches!(
+            self,
+            Self::X86_64(x86_64::Type::IRelative)
+                | Self::AArch64(aarch64::Type::IRelative)
+                | Self::RiscV(riscv::Type::IRelative)
+        )
+    }
+
+    /// Symbol address stored in a GOT entry
+    pub fn is_glob_dat(&self) -> bool {
+        matches!(
+            self,
+            Self::X86_64(x86_64::Type::GlobDat) | Self::AArch64(aarch64::Type::GlobDat)
+        )
+    }
+
+    /// Symbol address stored in a PLT slot, which may be bound lazily
+    pub fn is_jump_slot(&self) -> bool {
+        matches!(
+            self,
+            Self::X86_64(x86_64::Type::JumpSlot)
+                | Self::AArch64(aarch64::Type::JumpSlot)
+                | Self::RiscV(riscv::Type::JumpSlot)
+        )
+    }
+
+    /// Symbol contents copied from the defining object into the executable
+    pub fn is_copy(&self) -> bool {
+        matches!(
+            self,
+            Self::X86_64(x86_64::Type::Copy)
+                | Self::AArch64(aarch64::Type::Copy)
+                | Self::RiscV(riscv::Type::Copy)
+        )
+    }
+}
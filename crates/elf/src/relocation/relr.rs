@@ -0,0 +1,109 @@
+use crate::reader::Reader;
+use crate::result::Result;
+
+/// A `DT_RELR` table: addresses of relative relocations, packed as an address word followed
+/// by bitmap words flagging which of the next words also need relocating.
+#[derive(Debug, Copy, Clone)]
+pub struct Table<'a> {
+    reader: Reader<'a>,
+    offset: u64,
+    count: usize,
+}
+
+impl<'a> Table<'a> {
+    /// Bind the `size` bytes of RELR words found at `offset`
+    pub fn new(reader: Reader<'a>, offset: u64, size: u64) -> Result<Self> {
+        reader.slice(offset, size)?;
+
+        Ok(Self {
+            reader,
+            offset,
+            count: (size / reader.addr_size()) as usize,
+        })
+    }
+
+    pub fn empty(reader: Reader<'a>) -> Self {
+        Self {
+            reader,
+            offset: 0,
+            count: 0,
+        }
+    }
+
+    pub fn reader(&self) -> Reader<'a> {
+        self.reader
+    }
+
+    /// Number of encoded words, not of relocations
+    pub fn words(&self) -> usize {
+        self.count
+    }
+
+    /// Addresses of the places to relocate, in ascending order
+    pub fn iter(&self) -> Iter<'a> {
+        Iter {
+            table: *self,
+            index: 0,
+            base: 0,
+            run: 0,
+            bitmap: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for Table<'a> {
+    type Item = u64;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    table: Table<'a>,
+    index: usize,
+    base: u64,   /* Address the next bitmap word starts at */
+    run: u64,    /* Address the current bitmap word started at */
+    bitmap: u64, /* Bits of the current bitmap word still to visit */
+}
+
+impl Iterator for Iter<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let word_size = self.table.reader.addr_size();
+
+        loop {
+            if self.bitmap != 0 {
+                let bit = self.bitmap.trailing_zeros() as u64;
+                self.bitmap &= self.bitmap - 1;
+
+                return Some(self.run + bit * word_size);
+            }
+
+            if self.index >= self.table.count {
+                return None;
+            }
+
+            let word = self
+                .table
+                .reader
+                .addr(self.table.offset + self.index as u64 * word_size)
+                .ok()?;
+            self.index += 1;
+
+            if word & 1 == 0 {
+                // An address word relocates its own place, bitmaps then continue after it
+                self.base = word + word_size;
+                return Some(word);
+            }
+
+            // Bit n of a bitmap word stands for the word n - 1 places after its start
+            self.bitmap = word >> 1;
+            self.run = self.base;
+            self.base += (8 * word_size - 1) * word_size;
+        }
+    }
+}
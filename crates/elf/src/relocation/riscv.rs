@@ -0,0 +1,94 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [None, 0, "No relocation", R_RISCV_NONE],
+        [Abs32, 1, "Direct 32 bit", R_RISCV_32],
+        [Abs64, 2, "Direct 64 bit", R_RISCV_64],
+        [Relative, 3, "Adjust by program base", R_RISCV_RELATIVE],
+        [Copy, 4, "Copy symbol at runtime", R_RISCV_COPY],
+        [JumpSlot, 5, "Create PLT entry", R_RISCV_JUMP_SLOT],
+        [
+            TlsDtpMod32,
+            6,
+            "Module number, 32 bit",
+            R_RISCV_TLS_DTPMOD32
+        ],
+        [
+            TlsDtpMod64,
+            7,
+            "Module number, 64 bit",
+            R_RISCV_TLS_DTPMOD64
+        ],
+        [
+            TlsDtpRel32,
+            8,
+            "Module-relative offset, 32 bit",
+            R_RISCV_TLS_DTPREL32
+        ],
+        [
+            TlsDtpRel64,
+            9,
+            "Module-relative offset, 64 bit",
+            R_RISCV_TLS_DTPREL64
+        ],
+        [
+            TlsTpRel32,
+            10,
+            "TP-relative offset, 32 bit",
+            R_RISCV_TLS_TPREL32
+        ],
+        [
+            TlsTpRel64,
+            11,
+            "TP-relative offset, 64 bit",
+            R_RISCV_TLS_TPREL64
+        ],
+        [TlsDesc, 12, "TLS descriptor", R_RISCV_TLSDESC],
+        [Branch, 16, "PC-relative branch", R_RISCV_BRANCH],
+        [Jal, 17, "PC-relative jump", R_RISCV_JAL],
+        [Call, 18, "PC-relative call", R_RISCV_CALL],
+        [
+            CallPlt,
+            19,
+            "PC-relative call through PLT",
+            R_RISCV_CALL_PLT
+        ],
+        [GotHi20, 20, "PC-relative GOT reference", R_RISCV_GOT_HI20],
+        [PcrelHi20, 23, "PC-relative reference", R_RISCV_PCREL_HI20],
+        [
+            PcrelLo12I,
+            24,
+            "PC-relative reference, I-type",
+            R_RISCV_PCREL_LO12_I
+        ],
+        [
+            PcrelLo12S,
+            25,
+            "PC-relative reference, S-type",
+            R_RISCV_PCREL_LO12_S
+        ],
+        [Hi20, 26, "Absolute address", R_RISCV_HI20],
+        [Lo12I, 27, "Absolute address, I-type", R_RISCV_LO12_I],
+        [Lo12S, 28, "Absolute address, S-type", R_RISCV_LO12_S],
+        [Add32, 35, "32-bit label addition", R_RISCV_ADD32],
+        [Add64, 36, "64-bit label addition", R_RISCV_ADD64],
+        [Sub32, 39, "32-bit label subtraction", R_RISCV_SUB32],
+        [Sub64, 40, "64-bit label subtraction", R_RISCV_SUB64],
+        [Align, 43, "Alignment statement", R_RISCV_ALIGN],
+        [
+            RvcBranch,
+            44,
+            "PC-relative compressed branch",
+            R_RISCV_RVC_BRANCH
+        ],
+        [RvcJump, 45, "PC-relative compressed jump", R_RISCV_RVC_JUMP],
+        [Relax, 51, "Instruction can be relaxed", R_RISCV_RELAX],
+        [
+            IRelative,
+            58,
+            "Adjust indirectly by program base",
+            R_RISCV_IRELATIVE
+        ],
+    ]
+);
@@ -0,0 +1,117 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [None, 0, "No reloc", R_X86_64_NONE],
+        [Abs64, 1, "Direct 64 bit", R_X86_64_64],
+        [Pc32, 2, "PC relative 32 bit signed", R_X86_64_PC32],
+        [Got32, 3, "32 bit GOT entry", R_X86_64_GOT32],
+        [Plt32, 4, "32 bit PLT address", R_X86_64_PLT32],
+        [Copy, 5, "Copy symbol at runtime", R_X86_64_COPY],
+        [GlobDat, 6, "Create GOT entry", R_X86_64_GLOB_DAT],
+        [JumpSlot, 7, "Create PLT entry", R_X86_64_JUMP_SLOT],
+        [Relative, 8, "Adjust by program base", R_X86_64_RELATIVE],
+        [
+            GotPcRel,
+            9,
+            "32 bit signed PC relative offset to GOT",
+            R_X86_64_GOTPCREL
+        ],
+        [Abs32, 10, "Direct 32 bit zero extended", R_X86_64_32],
+        [Abs32S, 11, "Direct 32 bit sign extended", R_X86_64_32S],
+        [Abs16, 12, "Direct 16 bit zero extended", R_X86_64_16],
+        [Pc16, 13, "16 bit sign extended pc relative", R_X86_64_PC16],
+        [Abs8, 14, "Direct 8 bit sign extended", R_X86_64_8],
+        [Pc8, 15, "8 bit sign extended pc relative", R_X86_64_PC8],
+        [
+            DtpMod64,
+            16,
+            "ID of module containing symbol",
+            R_X86_64_DTPMOD64
+        ],
+        [
+            DtpOff64,
+            17,
+            "Offset in module's TLS block",
+            R_X86_64_DTPOFF64
+        ],
+        [TpOff64, 18, "Offset in initial TLS block", R_X86_64_TPOFF64],
+        [
+            TlsGd,
+            19,
+            "32 bit signed PC relative offset to two GOT entries for GD symbol",
+            R_X86_64_TLSGD
+        ],
+        [
+            TlsLd,
+            20,
+            "32 bit signed PC relative offset to two GOT entries for LD symbol",
+            R_X86_64_TLSLD
+        ],
+        [DtpOff32, 21, "Offset in TLS block", R_X86_64_DTPOFF32],
+        [
+            GotTpOff,
+            22,
+            "32 bit signed PC relative offset to GOT entry for IE symbol",
+            R_X86_64_GOTTPOFF
+        ],
+        [TpOff32, 23, "Offset in initial TLS block", R_X86_64_TPOFF32],
+        [Pc64, 24, "PC relative 64 bit", R_X86_64_PC64],
+        [GotOff64, 25, "64 bit offset to GOT", R_X86_64_GOTOFF64],
+        [
+            GotPc32,
+            26,
+            "32 bit signed pc relative offset to GOT",
+            R_X86_64_GOTPC32
+        ],
+        [
+            Size32,
+            32,
+            "Size of symbol plus 32 bit addend",
+            R_X86_64_SIZE32
+        ],
+        [
+            Size64,
+            33,
+            "Size of symbol plus 64 bit addend",
+            R_X86_64_SIZE64
+        ],
+        [
+            GotPc32TlsDesc,
+            34,
+            "GOT offset for TLS descriptor",
+            R_X86_64_GOTPC32_TLSDESC
+        ],
+        [
+            TlsDescCall,
+            35,
+            "Marker for call through TLS descriptor",
+            R_X86_64_TLSDESC_CALL
+        ],
+        [TlsDesc, 36, "TLS descriptor", R_X86_64_TLSDESC],
+        [
+            IRelative,
+            37,
+            "Adjust indirectly by program base",
+            R_X86_64_IRELATIVE
+        ],
+        [
+            Relative64,
+            38,
+            "64 bit adjust by program base",
+            R_X86_64_RELATIVE64
+        ],
+        [
+            GotPcRelX,
+            41,
+            "Load from 32 bit signed pc relative offset to GOT entry without REX prefix, relaxable",
+            R_X86_64_GOTPCRELX
+        ],
+        [
+            RexGotPcRelX,
+            42,
+            "Load from 32 bit signed pc relative offset to GOT entry with REX prefix, relaxable",
+            R_X86_64_REX_GOTPCRELX
+        ],
+    ]
+);
@@ -0,0 +1,73 @@
+define_error!(
+    Error,
+    Malformed,
+    [
+        [Truncated, -1, "Data shorter than expected"],
+        [Malformed, -2, "Malformed ELF structure"],
+        [InvalidMagic, -3, "Invalid ELF magic"],
+        [InvalidClass, -4, "Invalid ELF class"],
+        [UnsupportedClass, -5, "Unsupported ELF class"],
+        [InvalidData, -6, "Invalid data encoding"],
+        [InvalidVersion, -7, "Invalid ELF version"],
+        [InvalidOsAbi, -8, "Invalid OS/ABI"],
+        [InvalidHeaderSize, -9, "Invalid ELF header size"],
+        [
+            InvalidProgramHeaderSize,
+            -10,
+            "Invalid program header entry size"
+        ],
+        [
+            InvalidSectionHeaderSize,
+            -11,
+            "Invalid section header entry size"
+        ],
+        [InvalidString, -12, "String is not terminated or not UTF-8"],
+        [InvalidSectionIndex, -13, "Section index out of range"],
+        [MissingSection, -14, "Section not found"],
+        [
+            MissingStringTable,
+            -15,
+            "Section name string table not present"
+        ],
+        [
+            MissingExtendedCount,
+            -16,
+            "Extended count escape without section header 0"
+        ],
+        [MissingDynamic, -17, "Dynamic section not present"],
+        [
+            UnmappedAddress,
+            -18,
+            "Address not covered by any loadable segment"
+        ],
+        [
+            MissingDynamicStrings,
+            -19,
+            "Dynamic string table not present"
+        ],
+        [NotAString, -20, "Dynamic entry does not hold a string"],
+        [MissingSymbolTable, -21, "Symbol table not present"],
+        [MissingHashTable, -22, "Symbol hash table not present"],
+        [MissingVersionTable, -23, "Symbol version table not present"],
+        [InvalidVersionIndex, -24, "Version index not defined"],
+        [InvalidSymbolIndex, -25, "Symbol index out of range"],
+        [
+            MissingRelocations,
+            -26,
+            "Relocation table not present or incomplete"
+        ],
+        [InvalidCache, -27, "Not a dynamic linker cache"],
+        [
+            UndefinedSymbol,
+            -28,
+            "Symbol not defined in any object of the scope"
+        ],
+        [
+            InvalidScopeIndex,
+            -29,
+            "Object not part of the lookup scope"
+        ],
+    ]
+);
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,327 @@
+use crate::Elf;
+use crate::dynamic::Dynamic;
+use crate::hash::Hash;
+use crate::header::Type as ObjectType;
+use crate::result::{Error, Result};
+use crate::symbol::{self, Index, Symbol, Symbols, Visibility};
+use crate::version::{self, VER_NDX_GLOBAL, Versions};
+
+/// What a relocation needs a definition for, which narrows the candidates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Class {
+    Data, /* Any relocation but the two below */
+    Plt,  /* A procedure linkage table slot, which must not bind to another PLT entry */
+    Copy, /* A copy relocation, which must not bind to the object holding the copy */
+}
+
+/// The tables of an object that symbol lookups read, each parsed out of the object once.
+#[derive(Debug, Copy, Clone)]
+pub struct Tables<'a> {
+    pub dynamic: Dynamic<'a>,
+    pub symbols: Symbols<'a>,
+    pub hash: Option<Hash<'a>>,         /* None when it exports nothing */
+    pub versions: Option<Versions<'a>>, /* None when its symbols are unversioned */
+}
+
+impl<'a> Tables<'a> {
+    pub fn parse(elf: &Elf<'a>) -> Result<Self> {
+        let hash = match elf.hash_table() {
+            Ok(hash) => Some(hash),
+            Err(Error::MissingHashTable) => None,
+            Err(error) => return Err(error),
+        };
+        let versions = match elf.versions() {
+            Ok(versions) => Some(versions),
+            Err(Error::MissingVersionTable) => None,
+            Err(error) => return Err(error),
+        };
+
+        Ok(Self {
+            dynamic: elf.dynamic()?,
+            symbols: elf.dynamic_symbols()?,
+            hash,
+            versions,
+        })
+    }
+}
+
+/// A symbol reference: a name and the version the referencing object needs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Reference<'a> {
+    pub name: &'a str,
+    pub version: Option<&'a str>, /* Version needed, none for unversioned references */
+    pub hidden: bool,             /* Only that exact version will do */
+    pub weak: bool,               /* May stay unresolved */
+}
+
+impl<'a> Reference<'a> {
+    /// A strong reference written as `name`, `name@VERSION` or `name@@VERSION`
+    pub fn parse(reference: &'a str) -> Self {
+        let (name, version, _) = version::split(reference);
+
+        Self {
+            name,
+            version,
+            hidden: false,
+            weak: false,
+        }
+    }
+
+    /// The reference the dynamic symbol at `index` of the object with `tables` makes
+    pub fn of(tables: &Tables<'a>, index: usize) -> Result<Self> {
+        let symbol = tables.symbols.get(index).ok_or(Error::InvalidSymbolIndex)?;
+        let version = match tables.versions {
+            Some(versions) => Some(versions.version(index)?),
+            None => None,
+        };
+
+        Ok(Self {
+            name: tables.symbols.name(&symbol)?,
+            version: version.and_then(|version| version.name),
+            hidden: version.is_some_and(|version| version.hidden),
+            weak: symbol.is_weak(),
+        })
+    }
+}
+
+/// A definition found for a reference: the position of the object in the scope and the
+/// index of the symbol among its dynamic symbols.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Definition {
+    pub object: usize,
+    pub index: usize,
+    pub symbol: Symbol,
+}
+
+/// Objects searched for definitions, in the order their definitions take precedence:
+/// the program first, then the libraries breadth-first as they were loaded.
+pub trait Scope<'a> {
+    fn len(&self) -> usize;
+
+    fn get(&self, position: usize) -> Option<&Elf<'a>>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Tables of the object at `position`, which a scope that keeps its objects around
+    /// should parse once rather than on every lookup
+    fn tables(&self, position: usize) -> Result<Tables<'a>> {
+        Tables::parse(self.get(position).ok_or(Error::InvalidScopeIndex)?)
+    }
+
+    /// Whether the object at `position` exports canonical PLT entries, which a scope
+    /// that keeps its objects around should work out once rather than on every lookup
+    fn exports_plt(&self, position: usize) -> bool {
+        self.get(position).is_some_and(exports_plt)
+    }
+}
+
+impl<'a> Scope<'a> for [Elf<'a>] {
+    fn len(&self) -> usize {
+        <[Elf<'a>]>::len(self)
+    }
+
+    fn get(&self, position: usize) -> Option<&Elf<'a>> {
+        <[Elf<'a>]>::get(self, position)
+    }
+}
+
+/// Whether `symbol` is a definition other objects may bind to. Hidden and internal
+/// symbols stay within their object, and only code and data count.
+fn binds(symbol: &Symbol) -> bool {
+    let code_or_data = matches!(
+        symbol.stype,
+        symbol::Type::NoType
+            | symbol::Type::Object
+            | symbol::Type::Func
+            | symbol::Type::Common
+            | symbol::Type::Tls
+            | symbol::Type::GnuIFunc
+    );
+
+    // A zero value is a placeholder, unless absolute or a thread-local offset
+    let valued =
+        symbol.value != 0 || symbol.shndx == Index::Absolute || symbol.stype == symbol::Type::Tls;
+
+    symbol.is_exported() && code_or_data && valued
+}
+
+/// Whether the symbol at `index` has the version `reference` needs.
+///
+/// A versioned reference takes the definition of that version, or an unversioned one
+/// unless it must be exact. An unversioned reference, as made by objects linked before
+/// the library had versions, takes the base or oldest version; failing that, the one
+/// public version if there is only one, which `fallback` collects.
+fn versioned(
+    versions: &Versions,
+    index: usize,
+    reference: &Reference,
+    fallback: &mut (usize, Option<usize>),
+) -> bool {
+    let Some(versym) = versions.get(index) else {
+        return false;
+    };
+
+    match reference.version {
+        Some(name) => {
+            let found = versions
+                .version(index)
+                .ok()
+                .and_then(|version| version.name);
+            found == Some(name)
+                || !reference.hidden && versym.index() <= VER_NDX_GLOBAL && !versym.is_hidden()
+        }
+        None if versym.index() > VER_NDX_GLOBAL + 1 => {
+            if !versym.is_hidden() {
+                fallback.0 += 1;
+                fallback.1.get_or_insert(index);
+            }
+            false
+        }
+        None => true,
+    }
+}
+
+/// Definition of `reference` that the object with `tables` offers other objects
+pub fn find(tables: &Tables, reference: &Reference) -> Result<Option<(usize, Symbol)>> {
+    let Some(hash) = tables.hash else {
+        return Ok(None);
+    };
+    let symbols = tables.symbols;
+
+    let mut fallback = (0, None);
+    let found = hash.lookup(reference.name, &symbols, |index, symbol| {
+        binds(symbol)
+            && tables
+                .versions
+                .as_ref()
+                .is_none_or(|versions| versioned(versions, index, reference, &mut fallback))
+    });
+
+    if found.is_some() {
+        return Ok(found);
+    }
+
+    if let (1, Some(index)) = fallback {
+        return Ok(symbols.get(index).map(|symbol| (index, symbol)));
+    }
+
+    Ok(None)
+}
+
+/// Whether `symbol` is the PLT entry of a function a program at a fixed address takes
+/// the address of, exported as an undefined symbol with a value so every object agrees
+/// on the address
+fn canonical_plt(symbol: &Symbol) -> bool {
+    symbol.is_undefined()
+        && symbol.is_global()
+        && symbol.stype == symbol::Type::Func
+        && symbol.value != 0
+}
+
+/// Whether `elf` is a program linked at a fixed address with canonical PLT entries
+pub fn exports_plt(elf: &Elf) -> bool {
+    elf.header.etype == ObjectType::Exec
+        && elf
+            .dynamic_symbols()
+            .is_ok_and(|symbols| symbols.iter().any(|symbol| canonical_plt(&symbol)))
+}
+
+/// Canonical PLT entry the object with `tables` offers for `reference`, which the hash
+/// table leaves out as the symbol is undefined
+fn canonical(tables: &Tables, reference: &Reference) -> Option<(usize, Symbol)> {
+    let symbols = tables.symbols;
+
+    let mut fallback = (0, None);
+    symbols.iter().enumerate().find(|(index, symbol)| {
+        canonical_plt(symbol)
+            && symbols.name(symbol) == Ok(reference.name)
+            && tables
+                .versions
+                .as_ref()
+                .is_none_or(|versions| versioned(versions, *index, reference, &mut fallback))
+    })
+}
+
+/// First definition of `reference` in `scope`, passing over the object at `skip`.
+///
+/// The first definition wins whether it is weak or not, as glibc does unless
+/// `LD_DYNAMIC_WEAK` is set.
+pub fn lookup<'a, S: Scope<'a> + ?Sized>(
+    scope: &S,
+    reference: &Reference,
+    class: Class,
+    skip: Option<usize>,
+) -> Result<Option<Definition>> {
+    for object in (0..scope.len()).filter(|&object| Some(object) != skip) {
+        let tables = scope.tables(object)?;
+
+        // A program's canonical PLT entries stand for the function everywhere but in
+        // PLT slots, which must reach the function itself
+        let mut found = find(&tables, reference)?;
+        if found.is_none() && class != Class::Plt && scope.exports_plt(object) {
+            found = canonical(&tables, reference);
+        }
+
+        if let Some((index, symbol)) = found {
+            return Ok(Some(Definition {
+                object,
+                index,
+                symbol,
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Resolve the dynamic symbol at `index` of the object at `requester` in `scope`, as a
+/// relocation of `class` refers to it.
+///
+/// - local, hidden and protected definitions bind to the requester itself;
+/// - a requester linked with `DF_SYMBOLIC` binds to its own definitions first;
+/// - otherwise the first definition in the scope wins, which lets the program and
+///   earlier libraries interpose on later ones;
+/// - a copy relocation binds past the requester, which holds the copy;
+/// - a weak reference nothing defines is left unresolved.
+pub fn resolve<'a, S: Scope<'a> + ?Sized>(
+    scope: &S,
+    requester: usize,
+    index: usize,
+    class: Class,
+) -> Result<Option<Definition>> {
+    let tables = scope.tables(requester)?;
+    let symbol = tables.symbols.get(index).ok_or(Error::InvalidSymbolIndex)?;
+    let own = Definition {
+        object: requester,
+        index,
+        symbol,
+    };
+
+    let local =
+        !symbol.is_global() || symbol.is_defined() && symbol.visibility != Visibility::Default;
+    if local && class != Class::Copy {
+        return Ok(Some(own));
+    }
+
+    let reference = Reference::of(&tables, index)?;
+
+    if class != Class::Copy
+        && tables.dynamic.is_symbolic()
+        && let Some((index, symbol)) = find(&tables, &reference)?
+    {
+        return Ok(Some(Definition {
+            object: requester,
+            index,
+            symbol,
+        }));
+    }
+
+    let skip = (class == Class::Copy).then_some(requester);
+    match lookup(scope, &reference, class, skip)? {
+        Some(definition) => Ok(Some(definition)),
+        None if reference.weak => Ok(None),
+        None => Err(Error::UndefinedSymbol),
+    }
+}
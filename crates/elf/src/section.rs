@@ -0,0 +1,19 @@
+pub mod header;
+
+pub use header::{Flag, Flags, Header, Type};
+
+pub const SHN_UNDEF: u16 = 0; /* Undefined section */
+pub const SHN_LORESERVE: u16 = 0xff00; /* Start of reserved indices */
+pub const SHN_ABS: u16 = 0xfff1; /* Associated symbol is absolute */
+pub const SHN_COMMON: u16 = 0xfff2; /* Associated symbol is common */
+pub const SHN_XINDEX: u16 = 0xffff; /* Index is in extra table */
+
+/// The section header table
+pub type Table<'a> = crate::table::Table<'a, Header>;
+
+impl Table<'_> {
+    /// First section of the given type
+    pub fn find(&self, stype: Type) -> Option<Header> {
+        self.iter().find(|header| header.stype == stype)
+    }
+}
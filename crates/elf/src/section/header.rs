@@ -0,0 +1,61 @@
+pub mod flag;
+pub mod stype;
+
+pub use flag::{Flag, Flags};
+pub use stype::Type;
+
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::table::Entry;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Header {
+    pub name: u32,      /* Section name (string tbl index) */
+    pub stype: Type,    /* Section type */
+    pub flags: Flags,   /* Section flags */
+    pub addr: u64,      /* Section virtual addr at execution */
+    pub offset: u64,    /* Section file offset */
+    pub size: u64,      /* Section size in bytes */
+    pub link: u32,      /* Link to another section */
+    pub info: u32,      /* Additional section information */
+    pub addralign: u64, /* Section alignment */
+    pub entsize: u64,   /* Entry size if section holds table */
+}
+
+impl Entry for Header {
+    /// Decode the `Elf32_Shdr` or `Elf64_Shdr` found at `offset`
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        let size = reader.addr_size();
+
+        Ok(Self {
+            name: reader.u32(offset)?,
+            stype: Type::from(reader.u32(offset + 4)?),
+            flags: Flags::from(reader.addr(offset + 8)?),
+            addr: reader.addr(offset + 8 + size)?,
+            offset: reader.addr(offset + 8 + 2 * size)?,
+            size: reader.addr(offset + 8 + 3 * size)?,
+            link: reader.u32(offset + 8 + 4 * size)?,
+            info: reader.u32(offset + 12 + 4 * size)?,
+            addralign: reader.addr(offset + 16 + 4 * size)?,
+            entsize: reader.addr(offset + 16 + 5 * size)?,
+        })
+    }
+}
+
+impl Header {
+    /// Bytes of the section as stored in the file, empty for `SHT_NOBITS`
+    pub fn data<'a>(&self, reader: &Reader<'a>) -> Result<&'a [u8]> {
+        match self.stype {
+            Type::NoBits => Ok(&[]),
+            _ => reader.slice(self.offset, self.size),
+        }
+    }
+
+    /// Number of fixed-size entries held by the section
+    pub fn entry_count(&self) -> usize {
+        match self.entsize {
+            0 => 0,
+            entsize => (self.size / entsize) as usize,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+define_flags!(
+    Flags,
+    Flag,
+    u64,
+    [
+        [Write, 0x1, "Writable", SHF_WRITE],
+        [Alloc, 0x2, "Occupies memory during execution", SHF_ALLOC],
+        [ExecInstr, 0x4, "Executable", SHF_EXECINSTR],
+        [Merge, 0x10, "Might be merged", SHF_MERGE],
+        [
+            Strings,
+            0x20,
+            "Contains nul-terminated strings",
+            SHF_STRINGS
+        ],
+        [
+            InfoLink,
+            0x40,
+            "`sh_info' contains section index",
+            SHF_INFO_LINK
+        ],
+        [
+            LinkOrder,
+            0x80,
+            "Preserve order after combining",
+            SHF_LINK_ORDER
+        ],
+        [
+            OsNonconforming,
+            0x100,
+            "Non-standard OS specific handling required",
+            SHF_OS_NONCONFORMING
+        ],
+        [Group, 0x200, "Section is member of a group", SHF_GROUP],
+        [Tls, 0x400, "Section hold thread-local data", SHF_TLS],
+        [
+            Compressed,
+            0x800,
+            "Section with compressed data",
+            SHF_COMPRESSED
+        ],
+        [
+            GnuRetain,
+            0x200000,
+            "Not to be garbage collected by the linker",
+            SHF_GNU_RETAIN
+        ],
+        [
+            Exclude,
+            0x80000000,
+            "Excluded unless referenced or allocated",
+            SHF_EXCLUDE
+        ],
+    ]
+);
+
+pub const SHF_MASKOS: u64 = 0x0ff00000;
+pub const SHF_MASKPROC: u64 = 0xf0000000;
@@ -0,0 +1,104 @@
+define_type!(
+    Type,
+    u32,
+    [
+        [Null, 0, "Inactive section header", SHT_NULL],
+        [ProgBits, 1, "Program-defined contents", SHT_PROGBITS],
+        [SymTab, 2, "Symbol table", SHT_SYMTAB],
+        [StrTab, 3, "String table", SHT_STRTAB],
+        [Rela, 4, "Relocations with addends", SHT_RELA],
+        [Hash, 5, "Symbol hash table", SHT_HASH],
+        [Dynamic, 6, "Dynamic linking information", SHT_DYNAMIC],
+        [Note, 7, "Notes", SHT_NOTE],
+        [NoBits, 8, "Occupies no space in the file", SHT_NOBITS],
+        [Rel, 9, "Relocations without addends", SHT_REL],
+        [Shlib, 10, "Reserved", SHT_SHLIB],
+        [DynSym, 11, "Dynamic linker symbol table", SHT_DYNSYM],
+        [InitArray, 14, "Array of constructors", SHT_INIT_ARRAY],
+        [FiniArray, 15, "Array of destructors", SHT_FINI_ARRAY],
+        [
+            PreInitArray,
+            16,
+            "Array of pre-constructors",
+            SHT_PREINIT_ARRAY
+        ],
+        [Group, 17, "Section group", SHT_GROUP],
+        [
+            SymTabShndx,
+            18,
+            "Extended section indices",
+            SHT_SYMTAB_SHNDX
+        ],
+        [Relr, 19, "Relative relocations", SHT_RELR],
+        [
+            AndroidRel,
+            0x60000001,
+            "Android packed relocations",
+            SHT_ANDROID_REL
+        ],
+        [
+            AndroidRela,
+            0x60000002,
+            "Android packed relocations with addends",
+            SHT_ANDROID_RELA
+        ],
+        [
+            AndroidRelr,
+            0x6fffff00,
+            "Android relative relocations",
+            SHT_ANDROID_RELR
+        ],
+        [
+            GnuAttributes,
+            0x6ffffff5,
+            "Object attributes",
+            SHT_GNU_ATTRIBUTES
+        ],
+        [GnuHash, 0x6ffffff6, "GNU-style hash table", SHT_GNU_HASH],
+        [
+            GnuLibList,
+            0x6ffffff7,
+            "Prelink library list",
+            SHT_GNU_LIBLIST
+        ],
+        [
+            Checksum,
+            0x6ffffff8,
+            "Checksum for DSO content",
+            SHT_CHECKSUM
+        ],
+        [
+            GnuVerDef,
+            0x6ffffffd,
+            "Version definition section",
+            SHT_GNU_VERDEF
+        ],
+        [
+            GnuVerNeed,
+            0x6ffffffe,
+            "Version needs section",
+            SHT_GNU_VERNEED
+        ],
+        [
+            GnuVerSym,
+            0x6fffffff,
+            "Version symbol table",
+            SHT_GNU_VERSYM
+        ],
+    ]
+);
+
+pub const SHT_LOOS: u32 = 0x60000000;
+pub const SHT_HIOS: u32 = 0x6fffffff;
+pub const SHT_LOPROC: u32 = 0x70000000;
+pub const SHT_HIPROC: u32 = 0x7fffffff;
+
+impl Type {
+    pub fn is_os_specific(&self) -> bool {
+        (SHT_LOOS..=SHT_HIOS).contains(&self.to())
+    }
+
+    pub fn is_processor_specific(&self) -> bool {
+        (SHT_LOPROC..=SHT_HIPROC).contains(&self.to())
+    }
+}
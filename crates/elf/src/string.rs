@@ -0,0 +1,31 @@
+use crate::result::{Error, Result};
+
+/// A table of NUL-terminated strings referenced by offset.
+#[derive(Debug, Copy, Clone)]
+pub struct Table<'a> {
+    pub bytes: &'a [u8],
+}
+
+impl<'a> Table<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Get the string starting at `offset`
+    pub fn get(&self, offset: u64) -> Result<&'a str> {
+        let bytes = self.get_bytes(offset)?;
+        core::str::from_utf8(bytes).map_err(|_| Error::InvalidString)
+    }
+
+    /// Get the raw bytes of the string starting at `offset`, without its terminator
+    pub fn get_bytes(&self, offset: u64) -> Result<&'a [u8]> {
+        let start = usize::try_from(offset).map_err(|_| Error::Truncated)?;
+        let tail = self.bytes.get(start..).ok_or(Error::Truncated)?;
+        let length = tail
+            .iter()
+            .position(|&byte| byte == 0)
+            .ok_or(Error::InvalidString)?;
+
+        Ok(&tail[..length])
+    }
+}
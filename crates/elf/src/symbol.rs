@@ -0,0 +1,142 @@
+pub mod bind;
+pub mod index;
+pub mod stype;
+pub mod visibility;
+
+pub use bind::Bind;
+pub use index::Index;
+pub use stype::Type;
+pub use visibility::Visibility;
+
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::string;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Symbol {
+    pub name: u32,              /* Symbol name (string tbl index) */
+    pub bind: Bind,             /* Symbol binding */
+    pub stype: Type,            /* Symbol type */
+    pub visibility: Visibility, /* Symbol visibility */
+    pub shndx: Index,           /* Section index */
+    pub value: u64,             /* Symbol value */
+    pub size: u64,              /* Symbol size */
+}
+
+impl crate::table::Entry for Symbol {
+    /// Decode the `Elf32_Sym` or `Elf64_Sym` found at `offset`
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        let (info, other, shndx, value, size) = if reader.is_32() {
+            (
+                reader.u8(offset + 12)?,
+                reader.u8(offset + 13)?,
+                reader.u16(offset + 14)?,
+                reader.u32(offset + 4)? as u64,
+                reader.u32(offset + 8)? as u64,
+            )
+        } else {
+            (
+                reader.u8(offset + 4)?,
+                reader.u8(offset + 5)?,
+                reader.u16(offset + 6)?,
+                reader.u64(offset + 8)?,
+                reader.u64(offset + 16)?,
+            )
+        };
+
+        Ok(Self {
+            name: reader.u32(offset)?,
+            bind: Bind::from(info >> 4),
+            stype: Type::from(info & 0xf),
+            visibility: Visibility::from(other & 0x3),
+            shndx: Index::from(shndx),
+            value,
+            size,
+        })
+    }
+}
+
+impl Symbol {
+    pub fn is_undefined(&self) -> bool {
+        self.shndx == Index::Undefined
+    }
+
+    pub fn is_defined(&self) -> bool {
+        !self.is_undefined()
+    }
+
+    pub fn is_global(&self) -> bool {
+        matches!(self.bind, Bind::Global | Bind::Weak | Bind::GnuUnique)
+    }
+
+    pub fn is_weak(&self) -> bool {
+        self.bind == Bind::Weak
+    }
+
+    /// Whether other objects may bind to this definition
+    pub fn is_exported(&self) -> bool {
+        self.is_defined()
+            && self.is_global()
+            && matches!(self.visibility, Visibility::Default | Visibility::Protected)
+    }
+}
+
+/// Size of `Elf32_Sym` or `Elf64_Sym`
+pub fn entry_size(reader: &Reader) -> u64 {
+    if reader.is_32() { 16 } else { 24 }
+}
+
+/// The raw symbol table
+pub type Table<'a> = crate::table::Table<'a, Symbol>;
+
+/// A symbol table together with the string table its names refer to.
+#[derive(Debug, Copy, Clone)]
+pub struct Symbols<'a> {
+    pub table: Table<'a>,
+    pub strings: string::Table<'a>,
+}
+
+impl<'a> Symbols<'a> {
+    pub fn new(table: Table<'a>, strings: string::Table<'a>) -> Self {
+        Self { table, strings }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Symbol> {
+        self.table.get(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Symbol> + 'a {
+        self.table.iter()
+    }
+
+    pub fn name(&self, symbol: &Symbol) -> Result<&'a str> {
+        self.strings.get(symbol.name as u64)
+    }
+
+    /// First symbol with the given name and its index, by linear scan
+    pub fn find(&self, name: &str) -> Option<(usize, Symbol)> {
+        self.iter()
+            .enumerate()
+            .find(|(_, symbol)| self.name(symbol) == Ok(name))
+    }
+
+    /// Symbols other objects may bind to
+    pub fn exported(&self) -> impl Iterator<Item = Symbol> + 'a {
+        self.iter().filter(Symbol::is_exported)
+    }
+
+    /// Symbols that must be provided by some other object, skipping the null entry
+    pub fn undefined(&self) -> impl Iterator<Item = Symbol> + 'a {
+        self.iter()
+            .skip(1)
+            .filter(|symbol| symbol.is_undefined() && symbol.stype != Type::Section)
+    }
+}
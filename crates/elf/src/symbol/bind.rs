@@ -0,0 +1,10 @@
+define_type!(
+    Bind,
+    u8,
+    [
+        [Local, 0, "Local symbol", STB_LOCAL],
+        [Global, 1, "Global symbol", STB_GLOBAL],
+        [Weak, 2, "Weak symbol", STB_WEAK],
+        [GnuUnique, 10, "Unique symbol", STB_GNU_UNIQUE],
+    ]
+);
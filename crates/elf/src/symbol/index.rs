@@ -0,0 +1,46 @@
+use crate::section::{SHN_ABS, SHN_COMMON, SHN_LORESERVE, SHN_UNDEF, SHN_XINDEX};
+
+/// Meaning of the `st_shndx` field of a symbol.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Index {
+    Undefined,     /* Symbol is referenced but not defined here */
+    Absolute,      /* Value is absolute and not affected by relocation */
+    Common,        /* Unallocated common block */
+    Extended,      /* Real index is in the SHT_SYMTAB_SHNDX section */
+    Section(u16),  /* Defined relative to this section */
+    Reserved(u16), /* Other processor or environment specific index */
+}
+
+impl Index {
+    pub fn from(value: u16) -> Self {
+        match value {
+            SHN_UNDEF => Self::Undefined,
+            SHN_ABS => Self::Absolute,
+            SHN_COMMON => Self::Common,
+            SHN_XINDEX => Self::Extended,
+            index if index >= SHN_LORESERVE => Self::Reserved(index),
+            index => Self::Section(index),
+        }
+    }
+
+    pub fn to(self) -> u16 {
+        match self {
+            Self::Undefined => SHN_UNDEF,
+            Self::Absolute => SHN_ABS,
+            Self::Common => SHN_COMMON,
+            Self::Extended => SHN_XINDEX,
+            Self::Section(index) | Self::Reserved(index) => index,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Undefined => "UND",
+            Self::Absolute => "ABS",
+            Self::Common => "COM",
+            Self::Extended => "XINDEX",
+            Self::Section(_) => "Section",
+            Self::Reserved(_) => "Reserved",
+        }
+    }
+}
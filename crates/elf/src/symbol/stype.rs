@@ -0,0 +1,19 @@
+define_type!(
+    Type,
+    u8,
+    [
+        [NoType, 0, "Symbol type is unspecified", STT_NOTYPE],
+        [Object, 1, "Symbol is a data object", STT_OBJECT],
+        [Func, 2, "Symbol is a code object", STT_FUNC],
+        [Section, 3, "Symbol associated with a section", STT_SECTION],
+        [File, 4, "Symbol's name is file name", STT_FILE],
+        [Common, 5, "Symbol is a common data object", STT_COMMON],
+        [Tls, 6, "Symbol is thread-local data object", STT_TLS],
+        [
+            GnuIFunc,
+            10,
+            "Symbol is indirect code object",
+            STT_GNU_IFUNC
+        ],
+    ]
+);
@@ -0,0 +1,10 @@
+define_type!(
+    Visibility,
+    u8,
+    [
+        [Default, 0, "Default symbol visibility rules", STV_DEFAULT],
+        [Internal, 1, "Processor specific hidden class", STV_INTERNAL],
+        [Hidden, 2, "Sym unavailable in other modules", STV_HIDDEN],
+        [Protected, 3, "Not preemptible, not exported", STV_PROTECTED],
+    ]
+);
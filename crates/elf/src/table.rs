@@ -0,0 +1,111 @@
+use core::marker::PhantomData;
+
+use crate::reader::Reader;
+use crate::result::Result;
+
+/// A fixed-size record decoded from an ELF table.
+pub trait Entry: Sized {
+    /// Decode the entry found at `offset`
+    fn parse(reader: &Reader, offset: u64) -> Result<Self>;
+}
+
+/// A table of fixed-size entries, decoded lazily from the object bytes.
+#[derive(Debug)]
+pub struct Table<'a, T> {
+    reader: Reader<'a>,
+    offset: u64,
+    entry_size: u64,
+    count: usize,
+    entry: PhantomData<T>,
+}
+
+impl<T> Clone for Table<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Table<'_, T> {}
+
+impl<'a, T: Entry> Table<'a, T> {
+    /// Bind a table of `count` entries at `offset`, checking it lies within the object
+    pub fn new(reader: Reader<'a>, offset: u64, entry_size: u64, count: usize) -> Result<Self> {
+        reader.slice(offset, entry_size.saturating_mul(count as u64))?;
+
+        Ok(Self {
+            reader,
+            offset,
+            entry_size,
+            count,
+            entry: PhantomData,
+        })
+    }
+
+    pub fn empty(reader: Reader<'a>) -> Self {
+        Self {
+            reader,
+            offset: 0,
+            entry_size: 0,
+            count: 0,
+            entry: PhantomData,
+        }
+    }
+
+    pub fn reader(&self) -> Reader<'a> {
+        self.reader
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.count {
+            return None;
+        }
+
+        T::parse(&self.reader, self.offset + index as u64 * self.entry_size).ok()
+    }
+
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            table: *self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a, T: Entry> IntoIterator for Table<'a, T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, T> {
+    table: Table<'a, T>,
+    index: usize,
+}
+
+impl<T: Entry> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let entry = self.table.get(self.index)?;
+        self.index += 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.table.count.saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Entry> ExactSizeIterator for Iter<'_, T> {}
@@ -0,0 +1,188 @@
+pub mod definition;
+pub mod flag;
+pub mod need;
+
+pub use definition::{Definition, Definitions};
+pub use flag::{Flag, Flags};
+pub use need::{Auxiliary, Need, Needs};
+
+use core::marker::PhantomData;
+
+use crate::reader::Reader;
+use crate::result::{Error, Result};
+
+pub const VER_NDX_LOCAL: u16 = 0;
+pub const VER_NDX_GLOBAL: u16 = 1;
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+pub const VERSYM_VERSION: u16 = 0x7fff;
+
+/// A `.gnu.version` entry, giving the version index of the symbol at the same position.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Versym(pub u16);
+
+impl crate::table::Entry for Versym {
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        Ok(Self(reader.u16(offset)?))
+    }
+}
+
+impl Versym {
+    pub fn index(&self) -> u16 {
+        self.0 & VERSYM_VERSION
+    }
+
+    /// Whether the symbol is a non-default version, reachable only as `name@VERSION`
+    pub fn is_hidden(&self) -> bool {
+        self.0 & VERSYM_HIDDEN != 0
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.index() == VER_NDX_LOCAL
+    }
+
+    pub fn is_global(&self) -> bool {
+        self.index() == VER_NDX_GLOBAL
+    }
+}
+
+/// The raw `.gnu.version` array
+pub type Table<'a> = crate::table::Table<'a, Versym>;
+
+/// An entry of the `.gnu.version_d` and `.gnu.version_r` lists, linked through relative offsets.
+pub trait Linked: crate::table::Entry {
+    /// Offset from this entry to the next one, 0 at the end of the list
+    fn next(&self) -> u32;
+}
+
+/// Iterator over a linked version list, bounded by its `DT_VERDEFNUM` or `DT_VERNEEDNUM` count.
+#[derive(Debug, Clone)]
+pub struct Chain<'a, T> {
+    reader: Reader<'a>,
+    offset: u64,
+    remaining: usize,
+    entry: PhantomData<T>,
+}
+
+impl<'a, T: Linked> Chain<'a, T> {
+    pub fn new(reader: Reader<'a>, offset: u64, count: usize) -> Self {
+        Self {
+            reader,
+            offset,
+            remaining: count,
+            entry: PhantomData,
+        }
+    }
+}
+
+impl<T: Linked> Iterator for Chain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let entry = T::parse(&self.reader, self.offset).ok()?;
+
+        self.remaining = match entry.next() {
+            0 => 0,
+            _ => self.remaining - 1,
+        };
+        self.offset += entry.next() as u64;
+
+        Some(entry)
+    }
+}
+
+/// The version of a single dynamic symbol.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Version<'a> {
+    pub index: u16,            /* Index into the version definitions and needs */
+    pub hidden: bool,          /* Not the default version of the symbol */
+    pub name: Option<&'a str>, /* Version name, none for local and global symbols */
+    pub file: Option<&'a str>, /* Object expected to define a needed version */
+}
+
+/// The symbol versioning information of an object, indexed like its dynamic symbols.
+#[derive(Debug, Copy, Clone)]
+pub struct Versions<'a> {
+    pub symbols: Table<'a>,
+    pub definitions: Option<Definitions<'a>>,
+    pub needs: Option<Needs<'a>>,
+}
+
+impl<'a> Versions<'a> {
+    pub fn new(
+        symbols: Table<'a>,
+        definitions: Option<Definitions<'a>>,
+        needs: Option<Needs<'a>>,
+    ) -> Self {
+        Self {
+            symbols,
+            definitions,
+            needs,
+        }
+    }
+
+    /// Raw version entry of the dynamic symbol at `index`
+    pub fn get(&self, index: usize) -> Option<Versym> {
+        self.symbols.get(index)
+    }
+
+    /// Resolve the version of the dynamic symbol at `index` through the definitions and needs
+    pub fn version(&self, index: usize) -> Result<Version<'a>> {
+        let versym = self.get(index).ok_or(Error::InvalidSymbolIndex)?;
+        let mut version = Version {
+            index: versym.index(),
+            hidden: versym.is_hidden(),
+            name: None,
+            file: None,
+        };
+
+        if versym.is_local() || versym.is_global() {
+            return Ok(version);
+        }
+
+        if let Some(definitions) = self.definitions
+            && let Some(definition) = definitions.find(version.index)
+        {
+            version.name = Some(definitions.name(&definition)?);
+            return Ok(version);
+        }
+
+        if let Some(needs) = self.needs
+            && let Some((need, auxiliary)) = needs.find(version.index)
+        {
+            version.name = Some(needs.name(&auxiliary)?);
+            version.file = Some(needs.file(&need)?);
+            return Ok(version);
+        }
+
+        Err(Error::InvalidVersionIndex)
+    }
+
+    /// Whether the symbol at `index` answers a reference to `name@version`,
+    /// or to the default version when `version` is none
+    pub fn matches(&self, index: usize, version: Option<&str>, default: bool) -> bool {
+        let Ok(found) = self.version(index) else {
+            return false;
+        };
+
+        match version {
+            None => found.index != VER_NDX_LOCAL && !found.hidden,
+            Some(name) => found.name == Some(name) && !(default && found.hidden),
+        }
+    }
+}
+
+/// Split a `name@VERSION` or `name@@VERSION` reference into name, version and whether the
+/// default version is required
+pub fn split(reference: &str) -> (&str, Option<&str>, bool) {
+    match reference.split_once('@') {
+        None => (reference, None, false),
+        Some((name, version)) => match version.strip_prefix('@') {
+            Some(version) => (name, Some(version), true),
+            None => (name, Some(version), false),
+        },
+    }
+}
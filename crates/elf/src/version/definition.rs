@@ -0,0 +1,115 @@
+use super::{Chain, Flags, Linked};
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::string;
+
+/// A `.gnu.version_d` entry, a version the object itself defines.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Definition {
+    pub version: u16, /* Version revision */
+    pub flags: Flags, /* Version information */
+    pub ndx: u16,     /* Version index */
+    pub cnt: u16,     /* Number of associated aux entries */
+    pub hash: u32,    /* Version name hash value */
+    pub aux: u32,     /* Offset in bytes to verdaux array */
+    pub next: u32,    /* Offset in bytes to next verdef entry */
+    pub offset: u64,  /* Position of the entry in the object */
+}
+
+impl crate::table::Entry for Definition {
+    /// Decode the `Elf32_Verdef` or `Elf64_Verdef` found at `offset`, both sharing one layout
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        Ok(Self {
+            version: reader.u16(offset)?,
+            flags: Flags::from(reader.u16(offset + 2)?),
+            ndx: reader.u16(offset + 4)?,
+            cnt: reader.u16(offset + 6)?,
+            hash: reader.u32(offset + 8)?,
+            aux: reader.u32(offset + 12)?,
+            next: reader.u32(offset + 16)?,
+            offset,
+        })
+    }
+}
+
+impl Linked for Definition {
+    fn next(&self) -> u32 {
+        self.next
+    }
+}
+
+impl Definition {
+    /// Whether this entry names the object itself rather than a symbol version
+    pub fn is_base(&self) -> bool {
+        self.flags.contains(super::Flag::Base)
+    }
+}
+
+/// A `.gnu.version_d` auxiliary entry, naming a definition or one of its parents.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Auxiliary {
+    pub name: u32, /* Version or dependency names */
+    pub next: u32, /* Offset in bytes to next verdaux entry */
+}
+
+impl crate::table::Entry for Auxiliary {
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        Ok(Self {
+            name: reader.u32(offset)?,
+            next: reader.u32(offset + 4)?,
+        })
+    }
+}
+
+impl Linked for Auxiliary {
+    fn next(&self) -> u32 {
+        self.next
+    }
+}
+
+/// The version definitions list together with the string table naming them.
+#[derive(Debug, Copy, Clone)]
+pub struct Definitions<'a> {
+    pub reader: Reader<'a>,
+    pub offset: u64,
+    pub count: usize,
+    pub strings: string::Table<'a>,
+}
+
+impl<'a> Definitions<'a> {
+    pub fn new(reader: Reader<'a>, offset: u64, count: usize, strings: string::Table<'a>) -> Self {
+        Self {
+            reader,
+            offset,
+            count,
+            strings,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Definition> + 'a {
+        Chain::<Definition>::new(self.reader, self.offset, self.count)
+    }
+
+    /// The definition holding a version index
+    pub fn find(&self, ndx: u16) -> Option<Definition> {
+        self.iter().find(|definition| definition.ndx == ndx)
+    }
+
+    /// The version name first, followed by the versions it inherits from
+    pub fn names(&self, definition: &Definition) -> impl Iterator<Item = Result<&'a str>> + 'a {
+        let strings = self.strings;
+
+        Chain::<Auxiliary>::new(
+            self.reader,
+            definition.offset + definition.aux as u64,
+            definition.cnt as usize,
+        )
+        .map(move |auxiliary| strings.get(auxiliary.name as u64))
+    }
+
+    pub fn name(&self, definition: &Definition) -> Result<&'a str> {
+        self.names(definition)
+            .next()
+            .unwrap_or(Err(crate::result::Error::InvalidVersionIndex))
+    }
+}
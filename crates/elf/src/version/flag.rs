@@ -0,0 +1,10 @@
+define_flags!(
+    Flags,
+    Flag,
+    u16,
+    [
+        [Base, 0x1, "Version of the object itself", VER_FLG_BASE],
+        [Weak, 0x2, "Weak version identifier", VER_FLG_WEAK],
+        [Info, 0x4, "Reference is informational only", VER_FLG_INFO],
+    ]
+);
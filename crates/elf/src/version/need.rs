@@ -0,0 +1,113 @@
+use super::{Chain, Flags, Linked};
+use crate::reader::Reader;
+use crate::result::Result;
+use crate::string;
+
+/// A `.gnu.version_r` entry, an object whose versions this one depends on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Need {
+    pub version: u16, /* Version of structure */
+    pub cnt: u16,     /* Number of associated aux entries */
+    pub file: u32,    /* Offset of filename for this dependency */
+    pub aux: u32,     /* Offset in bytes to vernaux array */
+    pub next: u32,    /* Offset in bytes to next verneed entry */
+    pub offset: u64,  /* Position of the entry in the object */
+}
+
+impl crate::table::Entry for Need {
+    /// Decode the `Elf32_Verneed` or `Elf64_Verneed` found at `offset`, both sharing one layout
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        Ok(Self {
+            version: reader.u16(offset)?,
+            cnt: reader.u16(offset + 2)?,
+            file: reader.u32(offset + 4)?,
+            aux: reader.u32(offset + 8)?,
+            next: reader.u32(offset + 12)?,
+            offset,
+        })
+    }
+}
+
+impl Linked for Need {
+    fn next(&self) -> u32 {
+        self.next
+    }
+}
+
+/// A `.gnu.version_r` auxiliary entry, one version required from the object.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Auxiliary {
+    pub hash: u32,    /* Hash value of dependency name */
+    pub flags: Flags, /* Dependency specific information */
+    pub other: u16,   /* Version index assigned to the dependency */
+    pub name: u32,    /* Dependency name string offset */
+    pub next: u32,    /* Offset in bytes to next vernaux entry */
+}
+
+impl crate::table::Entry for Auxiliary {
+    fn parse(reader: &Reader, offset: u64) -> Result<Self> {
+        Ok(Self {
+            hash: reader.u32(offset)?,
+            flags: Flags::from(reader.u16(offset + 4)?),
+            other: reader.u16(offset + 6)?,
+            name: reader.u32(offset + 8)?,
+            next: reader.u32(offset + 12)?,
+        })
+    }
+}
+
+impl Linked for Auxiliary {
+    fn next(&self) -> u32 {
+        self.next
+    }
+}
+
+/// The version needs list together with the string table naming files and versions.
+#[derive(Debug, Copy, Clone)]
+pub struct Needs<'a> {
+    pub reader: Reader<'a>,
+    pub offset: u64,
+    pub count: usize,
+    pub strings: string::Table<'a>,
+}
+
+impl<'a> Needs<'a> {
+    pub fn new(reader: Reader<'a>, offset: u64, count: usize, strings: string::Table<'a>) -> Self {
+        Self {
+            reader,
+            offset,
+            count,
+            strings,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Need> + 'a {
+        Chain::<Need>::new(self.reader, self.offset, self.count)
+    }
+
+    /// Versions required from one object
+    pub fn auxiliaries(&self, need: &Need) -> impl Iterator<Item = Auxiliary> + 'a {
+        Chain::<Auxiliary>::new(
+            self.reader,
+            need.offset + need.aux as u64,
+            need.cnt as usize,
+        )
+    }
+
+    /// The object and required version holding a version index
+    pub fn find(&self, other: u16) -> Option<(Need, Auxiliary)> {
+        self.iter().find_map(|need| {
+            self.auxiliaries(&need)
+                .find(|auxiliary| auxiliary.other == other)
+                .map(|auxiliary| (need, auxiliary))
+        })
+    }
+
+    pub fn file(&self, need: &Need) -> Result<&'a str> {
+        self.strings.get(need.file as u64)
+    }
+
+    pub fn name(&self, auxiliary: &Auxiliary) -> Result<&'a str> {
+        self.strings.get(auxiliary.name as u64)
+    }
+}
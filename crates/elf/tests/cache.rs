@@ -0,0 +1,162 @@
+use elf::Error;
+use elf::cache::{Abi, Cache, Format, Kind};
+
+// Seven entries: libbar.so.2 for AArch64 and as plain ELF, libfoo.so.1 in the
+// x86-64-v3 and x86-64-v2 glibc-hwcaps subdirectories and outside any, liblegacy.so
+// needing a legacy hardware capability and libx32.so.1 for x32.
+const GLIBC: &[u8] = include_bytes!("fixtures/glibc.cache");
+
+// libc.so.5 for libc5 and libfoo.so.1 for x86_64 libc6.
+const LEGACY: &[u8] = include_bytes!("fixtures/legacy.cache");
+
+// The two libraries of LEGACY, followed by a glibc part holding only libfoo.so.1.
+const COMBINED: &[u8] = include_bytes!("fixtures/combined.cache");
+
+// libbar.so.2 for AArch64, most significant byte first.
+const BIG_ENDIAN: &[u8] = include_bytes!("fixtures/big-endian.cache");
+
+const SUPPORTED: [&str; 2] = ["x86-64-v3", "x86-64-v2"];
+
+fn path<'a>(cache: &Cache<'a>, name: &str, abi: Abi, supported: &[&str]) -> Option<&'a str> {
+    cache
+        .lookup(name, abi, supported)
+        .unwrap()
+        .map(|entry| entry.value)
+}
+
+#[test]
+fn glibc_entries_are_read_in_order() {
+    let cache = Cache::parse(GLIBC).unwrap();
+
+    assert_eq!(cache.format, Format::Glibc);
+    assert_eq!(cache.len(), 7);
+
+    let first = cache.get(0).unwrap();
+    assert_eq!(first.key, "libbar.so.2");
+    assert_eq!(first.value, "/usr/lib/aarch64-linux-gnu/libbar.so.2");
+    assert_eq!(first.kind(), Kind::ElfLibc6);
+    assert_eq!(first.abi(), Abi::AArch64Lib64);
+
+    let keys: Vec<_> = cache.iter().map(|entry| entry.unwrap().key).collect();
+    assert_eq!(keys[2..5], ["libfoo.so.1"; 3]);
+}
+
+#[test]
+fn glibc_hwcaps_names_come_from_the_extension_area() {
+    let cache = Cache::parse(GLIBC).unwrap();
+
+    assert_eq!(cache.get(2).unwrap().hwcaps_index(), Some(0));
+    assert_eq!(cache.get(3).unwrap().hwcaps_index(), Some(1));
+    assert_eq!(cache.get(4).unwrap().hwcaps_index(), None);
+    assert_eq!(cache.hwcaps(0), Ok("x86-64-v3"));
+    assert_eq!(cache.hwcaps(1), Ok("x86-64-v2"));
+    assert_eq!(cache.hwcaps(2), Err(Error::Truncated));
+}
+
+#[test]
+fn lookup_prefers_the_best_supported_hwcaps_subdirectory() {
+    let cache = Cache::parse(GLIBC).unwrap();
+    let lookup = |supported: &[&str]| path(&cache, "libfoo.so.1", Abi::X8664Lib64, supported);
+
+    assert_eq!(
+        lookup(&SUPPORTED),
+        Some("/usr/lib/glibc-hwcaps/x86-64-v3/libfoo.so.1")
+    );
+    assert_eq!(
+        lookup(&["x86-64-v2"]),
+        Some("/usr/lib/glibc-hwcaps/x86-64-v2/libfoo.so.1")
+    );
+    assert_eq!(lookup(&[]), Some("/usr/lib/libfoo.so.1"));
+}
+
+#[test]
+fn lookup_filters_by_architecture_flags() {
+    let cache = Cache::parse(GLIBC).unwrap();
+
+    // Plain ELF entries suit every ABI, libc6 ones only their own
+    assert_eq!(
+        path(&cache, "libbar.so.2", Abi::X8664Lib64, &SUPPORTED),
+        Some("/lib/libbar.so.2")
+    );
+    assert_eq!(
+        cache
+            .lookup("libbar.so.2", Abi::AArch64Lib64, &[])
+            .unwrap()
+            .map(|entry| entry.abi()),
+        Some(Abi::AArch64Lib64)
+    );
+    assert_eq!(
+        path(&cache, "libx32.so.1", Abi::X8664Lib64, &SUPPORTED),
+        None
+    );
+    assert_eq!(
+        path(&cache, "libx32.so.1", Abi::X8664LibX32, &SUPPORTED),
+        Some("/usr/libx32/libx32.so.1")
+    );
+}
+
+#[test]
+fn lookup_skips_legacy_hwcaps_and_unknown_names() {
+    let cache = Cache::parse(GLIBC).unwrap();
+
+    assert_eq!(
+        path(&cache, "liblegacy.so", Abi::X8664Lib64, &SUPPORTED),
+        None
+    );
+    assert_eq!(
+        path(&cache, "libmissing.so", Abi::X8664Lib64, &SUPPORTED),
+        None
+    );
+}
+
+#[test]
+fn legacy_entries_have_no_hwcaps() {
+    let cache = Cache::parse(LEGACY).unwrap();
+
+    assert_eq!(cache.format, Format::Legacy);
+    assert_eq!(cache.len(), 2);
+
+    let libc = cache.get(0).unwrap();
+    assert_eq!((libc.key, libc.value), ("libc.so.5", "/lib/libc.so.5"));
+    assert_eq!(libc.kind(), Kind::ElfLibc5);
+    assert_eq!(libc.hwcap, 0);
+
+    assert_eq!(
+        path(&cache, "libfoo.so.1", Abi::X8664Lib64, &SUPPORTED),
+        Some("/lib/libfoo.so.1")
+    );
+    assert_eq!(path(&cache, "libc.so.5", Abi::X8664Lib64, &SUPPORTED), None);
+}
+
+#[test]
+fn combined_files_are_read_through_their_glibc_part() {
+    let cache = Cache::parse(COMBINED).unwrap();
+
+    assert_eq!(cache.format, Format::Glibc);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+        path(&cache, "libfoo.so.1", Abi::X8664Lib64, &SUPPORTED),
+        Some("/usr/lib/libfoo.so.1")
+    );
+}
+
+#[test]
+fn glibc_byte_order_follows_the_header_flags() {
+    let cache = Cache::parse(BIG_ENDIAN).unwrap();
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(
+        path(&cache, "libbar.so.2", Abi::AArch64Lib64, &[]),
+        Some("/usr/lib/aarch64-linux-gnu/libbar.so.2")
+    );
+}
+
+#[test]
+fn malformed_caches_are_rejected() {
+    assert_eq!(
+        Cache::parse(b"not a cache").unwrap_err(),
+        Error::InvalidCache
+    );
+    assert_eq!(Cache::parse(&GLIBC[..60]).unwrap_err(), Error::Truncated);
+    assert_eq!(Cache::parse(&LEGACY[..20]).unwrap_err(), Error::Truncated);
+}
@@ -0,0 +1,72 @@
+use elf::Elf;
+use elf::dynamic::Tag;
+use elf::program::Type;
+
+// Its writable segment sits a page further in memory than in the file, as does the
+// dynamic section within it.
+const A00_LS: &[u8] = include_bytes!("../../../data/misc/a00_ls");
+
+// Built from fixtures/lazy.c
+const LAZY: &[u8] = include_bytes!("fixtures/lazy");
+
+/// Lay the loadable segments of `bytes` out at their virtual addresses, as a loader maps
+/// them, with the bss zeroed
+fn map(bytes: &[u8]) -> Vec<u8> {
+    let elf = Elf::parse(bytes).unwrap();
+    let loads: Vec<_> = elf.program_headers().unwrap().loads().collect();
+    let end = loads
+        .iter()
+        .map(|load| load.vaddr + load.memsz)
+        .max()
+        .unwrap();
+
+    let mut image = vec![0; end as usize];
+    for load in loads {
+        let source = &bytes[load.offset as usize..][..load.filesz as usize];
+        image[load.vaddr as usize..][..source.len()].copy_from_slice(source);
+    }
+    image
+}
+
+#[test]
+fn mapped_images_find_the_dynamic_section_at_its_address() {
+    let file = Elf::parse(A00_LS).unwrap();
+    let dynamic = file.program_headers().unwrap().find(Type::Dynamic).unwrap();
+    assert_ne!(dynamic.offset, dynamic.vaddr);
+
+    let image = map(A00_LS);
+    let mapped = unsafe { Elf::from_image(image.as_ptr()) }.unwrap();
+    assert_eq!(mapped.bias(), image.as_ptr() as u64);
+
+    let expected: Vec<_> = file.dynamic().unwrap().iter().collect();
+    let found: Vec<_> = mapped.dynamic().unwrap().iter().collect();
+    assert_eq!(found, expected);
+
+    let needed: Vec<_> = mapped.dynamic().unwrap().needed().collect();
+    assert_eq!(needed, [Ok("libcap.so.2"), Ok("libc.so.6")]);
+    assert_eq!(mapped.dynamic().unwrap().get(Tag::InitArray), Some(0x20f10));
+}
+
+#[test]
+fn mapped_images_count_their_dynamic_symbols_from_the_hash_table() {
+    let image = map(A00_LS);
+    let mapped = unsafe { Elf::from_image(image.as_ptr()) }.unwrap();
+    let file = Elf::parse(A00_LS).unwrap();
+
+    assert_eq!(
+        mapped.dynamic_symbols().unwrap().len(),
+        file.dynamic_symbols().unwrap().len()
+    );
+}
+
+#[test]
+fn mapped_images_count_the_unhashed_symbols_relocations_refer_to() {
+    // Its one dynamic symbol is undefined and comes after the GNU hash table's
+    // `symoffset`, so the table itself covers none
+    let image = map(LAZY);
+    let mapped = unsafe { Elf::from_image(image.as_ptr()) }.unwrap();
+
+    let symbols = mapped.dynamic_symbols().unwrap();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols.find("quadruple").map(|(index, _)| index), Some(1));
+}
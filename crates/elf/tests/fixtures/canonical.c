@@ -0,0 +1,17 @@
+/*
+ * A program linked at a fixed address that takes the address of a function of
+ * liblink.so in its code, so the function's PLT entry becomes its canonical address,
+ * built with
+ *
+ *   gcc -no-pie -fno-pic -nostdlib -O1 -o canonical canonical.c -L. -llink
+ */
+
+long twice(long value);
+
+long (*address(void))(long) { return twice; }
+
+void _start(void)
+{
+    for (;;)
+        ;
+}
@@ -0,0 +1,90 @@
+/*
+ * A library and a program that call through PLT slots xelf binds on their first call,
+ * built without the C library with
+ *
+ *   gcc -shared -fPIC -nostdlib -O1 -z lazy -DLIBRARY -o liblazy.so lazy.c \
+ *       -L. -llink -Wl,-rpath,'$ORIGIN'
+ *   gcc -pie -fPIE -nostdlib -O1 -z lazy -o lazy lazy.c -L. -llazy -Wl,-rpath,'$ORIGIN'
+ *   gcc -pie -fPIE -nostdlib -O1 -z now -o lazy-now lazy.c -L. -llazy -Wl,-rpath,'$ORIGIN'
+ *
+ * Each object has a single PLT slot, the first after the three words PLT0 uses. Until
+ * it is bound, the slot points back into the object's own PLT. The program exits with
+ * 1 and 2 when its slot and the library's were bound before their first call, 4 and 8
+ * when they were still unbound after it, and 16 for wrong results.
+ */
+
+extern char __ehdr_start[] __attribute__((visibility("hidden")));
+extern char _end[] __attribute__((visibility("hidden")));
+
+/* Whether the object's PLT slot reaches out of the object */
+static int slot_bound(void)
+{
+    char *volatile *got;
+    __asm__("lea _GLOBAL_OFFSET_TABLE_(%%rip), %0" : "=r"(got));
+    char *target = got[3];
+
+    return target < __ehdr_start || target >= _end;
+}
+
+#ifdef LIBRARY
+
+/* From liblink.so */
+long twice(long value);
+
+/* Calls `twice` through the library's PLT slot, telling whether the slot was bound
+ * before and after */
+long quadruple(long value, int bound[2])
+{
+    bound[0] = slot_bound();
+    value = twice(twice(value));
+    bound[1] = slot_bound();
+
+    return value;
+}
+
+#else
+
+long quadruple(long value, int bound[2]);
+
+static long sys(long number, long a, long b, long c)
+{
+    long result;
+    __asm__ volatile("syscall"
+                     : "=a"(result)
+                     : "a"(number), "D"(a), "S"(b), "d"(c)
+                     : "rcx", "r11", "memory");
+    return result;
+}
+
+void run(void)
+{
+    int status = 0;
+    int bound[2];
+
+    if (slot_bound())
+        status |= 1;
+
+    if (quadruple(5, bound) != 20)
+        status |= 16;
+    if (bound[0])
+        status |= 2;
+    if (!bound[1])
+        status |= 8;
+    if (!slot_bound())
+        status |= 4;
+
+    if (quadruple(10, bound) != 40)
+        status |= 16;
+
+    sys(60, status, 0, 0);
+}
+
+/* The kernel leaves the stack 16-byte aligned, where a call would leave it off by 8 */
+__asm__(".globl _start\n"
+        "_start:\n"
+        "    xor %ebp, %ebp\n"
+        "    and $-16, %rsp\n"
+        "    call run\n"
+        "    hlt\n");
+
+#endif
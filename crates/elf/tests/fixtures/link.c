@@ -0,0 +1,68 @@
+/*
+ * A library and a program linked by xelf alone, built without the C library with
+ *
+ *   gcc -shared -fPIC -nostdlib -O1 -DLIBRARY -o liblink.so link.c
+ *   gcc -pie -fPIE -nostdlib -O1 -o link link.c -L. -llink -Wl,-rpath,'$ORIGIN'
+ *
+ * The program exits with 0 when both constructors ran and the library's data and
+ * function are reached, and with a bit set for each check that failed otherwise.
+ */
+
+#ifdef LIBRARY
+
+int counter = 40;
+
+static int constructed;
+
+__attribute__((constructor)) static void construct(void) { constructed = 1; }
+
+int library_constructed(void) { return constructed; }
+
+long twice(long value) { return 2 * value; }
+
+#else
+
+extern int counter;
+int library_constructed(void);
+long twice(long value);
+
+static long sys(long number, long a, long b, long c)
+{
+    long result;
+    __asm__ volatile("syscall"
+                     : "=a"(result)
+                     : "a"(number), "D"(a), "S"(b), "d"(c)
+                     : "rcx", "r11", "memory");
+    return result;
+}
+
+static int constructed;
+
+__attribute__((constructor)) static void construct(void) { constructed = 1; }
+
+void run(void)
+{
+    int status = 0;
+
+    if (!library_constructed())
+        status |= 1;
+    if (!constructed)
+        status |= 2;
+    if (counter != 40)
+        status |= 4;
+    if (twice(21) != 42)
+        status |= 8;
+
+    sys(1, 1, (long)"linked\n", 7);
+    sys(60, status, 0, 0);
+}
+
+/* The kernel leaves the stack 16-byte aligned, where a call would leave it off by 8 */
+__asm__(".globl _start\n"
+        "_start:\n"
+        "    xor %ebp, %ebp\n"
+        "    and $-16, %rsp\n"
+        "    call run\n"
+        "    hlt\n");
+
+#endif
@@ -0,0 +1,21 @@
+/*
+ * Libraries for the symbol resolution tests, built with
+ *
+ *   gcc -shared -fPIC -nostdlib -O1 -DWEAK -o scope.so scope.c
+ *   gcc -shared -fPIC -nostdlib -O1 -Wl,-Bsymbolic -o scope-symbolic.so scope.c
+ */
+
+int shared = 1;
+
+#ifdef WEAK
+__attribute__((weak))
+#endif
+int overridable = 2;
+
+__attribute__((visibility("protected"))) int protected_function(void) { return 3; }
+
+__attribute__((visibility("hidden"))) int hidden_function(void) { return 4; }
+
+int *shared_address(void) { return &shared; }
+
+int call_hidden(void) { return hidden_function(); }
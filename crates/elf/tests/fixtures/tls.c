@@ -0,0 +1,41 @@
+/*
+ * A library and a glibc program that reach thread-local variables through every TLS
+ * relocation type, built with
+ *
+ *   gcc -shared -fPIC -O1 -DLIBRARY -o libtls.so tls.c
+ *   gcc -pie -fPIE -O1 -o tls tls.c -L. -ltls -Wl,-rpath,'$ORIGIN'
+ *
+ * The library takes its own variable through `__tls_get_addr`, with R_X86_64_DTPMOD64
+ * and R_X86_64_DTPOFF64, and the program the library's through R_X86_64_TPOFF64. The
+ * program exits with a bit set for each check that failed.
+ */
+
+#ifdef LIBRARY
+
+__thread int counter = 40;
+__thread char padding[100];
+
+int *counter_address(void) { return &counter; }
+
+#else
+
+extern __thread int counter;
+__thread long own = 7;
+int *counter_address(void);
+
+int main(void)
+{
+    int status = 0;
+
+    if (counter != 40)
+        status |= 1;
+    if (own != 7)
+        status |= 2;
+    counter += 2;
+    if (counter_address() != &counter || *counter_address() != 42)
+        status |= 4;
+
+    return status;
+}
+
+#endif
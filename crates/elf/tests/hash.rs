@@ -0,0 +1,61 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use elf::dynamic::Tag;
+use elf::{Elf, Error};
+
+const STRIPPED: &[u8] = include_bytes!("../../../data/misc/stripped.x86_64.so");
+
+/// `AT_SYSINFO_EHDR`, where the kernel announces the vDSO
+const AT_SYSINFO_EHDR: u64 = 33;
+
+/// Address of the vDSO of this process, read from its auxiliary vector
+fn vdso() -> Option<u64> {
+    let auxv = std::fs::read("/proc/self/auxv").ok()?;
+
+    auxv.chunks_exact(16)
+        .map(|entry| {
+            let word = |at: usize| u64::from_ne_bytes(entry[at..at + 8].try_into().unwrap());
+            (word(0), word(8))
+        })
+        .find(|&(atype, _)| atype == AT_SYSINFO_EHDR)
+        .map(|(_, value)| value)
+}
+
+#[test]
+fn oversized_bloom_shifts_are_rejected() {
+    let elf = Elf::parse(STRIPPED).unwrap();
+    let table = elf.dynamic().unwrap().get(Tag::GnuHash).unwrap();
+    let shift = elf.offset_of(table).unwrap() as usize + 12;
+
+    let mut bytes = STRIPPED.to_vec();
+    bytes[shift..shift + 4].copy_from_slice(&32u32.to_le_bytes());
+
+    let elf = Elf::parse(&bytes).unwrap();
+    assert_eq!(elf.hash_table().unwrap_err(), Error::Malformed);
+    assert_eq!(elf.lookup("use_memset").unwrap_err(), Error::Malformed);
+}
+
+#[test]
+fn vdso_functions_are_found_through_the_hash_table() {
+    let Some(address) = vdso() else {
+        return;
+    };
+
+    // The kernel keeps the vDSO mapped for the whole life of the process
+    let vdso = unsafe { Elf::from_image(address as *const u8) }.unwrap();
+    let (_, symbol) = vdso.lookup("__vdso_clock_gettime").unwrap().unwrap();
+    assert_eq!(
+        vdso.lookup("__vdso_clock_gettime@LINUX_2.6")
+            .unwrap()
+            .map(|(_, found)| found),
+        Some(symbol)
+    );
+
+    let clock_gettime: extern "C" fn(i32, *mut [i64; 2]) -> i32 =
+        unsafe { std::mem::transmute(vdso.bias().wrapping_add(symbol.value)) };
+    let mut time = [0; 2];
+    assert_eq!(clock_gettime(0, &mut time), 0);
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    assert!(now.as_secs().abs_diff(time[0] as u64) <= 1);
+}
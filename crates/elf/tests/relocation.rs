@@ -0,0 +1,88 @@
+use elf::Elf;
+use elf::header::Machine;
+use elf::relocation::{Kind, Relocation, aarch64, riscv};
+
+const AARCH64: &[u8] = include_bytes!("../../../data/misc/symver.aarch64.so");
+const ARMHF: &[u8] = include_bytes!("../../../data/misc/symver.armhf.so");
+const RISCV64: &[u8] = include_bytes!("../../../data/misc/symver.riscv64.so");
+
+fn relocation(offset: u64, symbol: u32, kind: Kind, addend: i64) -> Relocation {
+    Relocation {
+        offset,
+        symbol,
+        kind,
+        addend: Some(addend),
+    }
+}
+
+#[test]
+fn aarch64_types_are_decoded() {
+    let elf = Elf::parse(AARCH64).unwrap();
+    let kind = Kind::AArch64;
+
+    let dynamic: Vec<_> = elf.dynamic_relocations().unwrap().iter().collect();
+    assert_eq!(
+        dynamic,
+        [
+            relocation(0x10dc0, 0, kind(aarch64::Type::Relative), 0x710),
+            relocation(0x10dc8, 0, kind(aarch64::Type::Relative), 0x6c0),
+            relocation(0x11018, 0, kind(aarch64::Type::Relative), 0x11018),
+            relocation(0x10fc8, 3, kind(aarch64::Type::GlobDat), 0),
+            relocation(0x10fd0, 4, kind(aarch64::Type::GlobDat), 0),
+            relocation(0x10fd8, 6, kind(aarch64::Type::GlobDat), 0),
+            relocation(0x10fe0, 7, kind(aarch64::Type::GlobDat), 0),
+        ]
+    );
+
+    let plt: Vec<_> = elf.plt_relocations().unwrap().iter().collect();
+    assert_eq!(
+        plt,
+        [
+            relocation(0x11000, 4, kind(aarch64::Type::JumpSlot), 0),
+            relocation(0x11008, 5, kind(aarch64::Type::JumpSlot), 0),
+            relocation(0x11010, 6, kind(aarch64::Type::JumpSlot), 0),
+        ]
+    );
+
+    assert_eq!(dynamic[0].kind.name(), "R_AARCH64_RELATIVE");
+    assert!(dynamic[3].kind.is_glob_dat());
+    assert!(plt[0].kind.is_jump_slot());
+}
+
+#[test]
+fn riscv_types_are_decoded() {
+    let elf = Elf::parse(RISCV64).unwrap();
+    let kind = Kind::RiscV;
+
+    let dynamic: Vec<_> = elf.dynamic_relocations().unwrap().iter().collect();
+    assert_eq!(
+        dynamic,
+        [
+            relocation(0x1e20, 0, kind(riscv::Type::Relative), 0x5e8),
+            relocation(0x1e28, 0, kind(riscv::Type::Relative), 0x5ae),
+            relocation(0x2000, 0, kind(riscv::Type::Relative), 0x2000),
+            relocation(0x2028, 2, kind(riscv::Type::Abs64), 0),
+            relocation(0x2030, 3, kind(riscv::Type::Abs64), 0),
+            relocation(0x2038, 4, kind(riscv::Type::Abs64), 0),
+            // Its linker counts `.rela.plt`, which follows, in `DT_RELASZ`
+            relocation(0x2018, 5, kind(riscv::Type::JumpSlot), 0),
+        ]
+    );
+
+    let plt: Vec<_> = elf.plt_relocations().unwrap().iter().collect();
+    assert_eq!(plt, [relocation(0x2018, 5, kind(riscv::Type::JumpSlot), 0)]);
+
+    assert_eq!(dynamic[3].kind.name(), "R_RISCV_64");
+    assert!(dynamic[0].kind.is_relative());
+    assert!(plt[0].kind.is_jump_slot());
+}
+
+#[test]
+fn types_of_machines_without_a_decoder_are_kept_as_numbers() {
+    let elf = Elf::parse(ARMHF).unwrap();
+
+    // R_ARM_RELATIVE, read from a REL table without addends
+    let first = elf.dynamic_relocations().unwrap().iter().next().unwrap();
+    assert_eq!(first.kind, Kind::Unknown(Machine::Arm, 23));
+    assert_eq!(first.addend, None);
+}
@@ -0,0 +1,124 @@
+use elf::header::Machine;
+use elf::header::ident::Class;
+use elf::relocation::{Kind, Relocations, Table, android, relr, x86_64};
+use elf::{Endianness, Reader};
+
+fn words64(words: &[u64]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+fn decode(reader: Reader) -> Vec<u64> {
+    relr::Table::new(reader, 0, reader.bytes.len() as u64)
+        .unwrap()
+        .iter()
+        .collect()
+}
+
+#[test]
+fn address_words_relocate_their_own_place() {
+    let bytes = words64(&[0x1000, 0x2000]);
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+
+    assert_eq!(decode(reader), [0x1000, 0x2000]);
+}
+
+#[test]
+fn bitmap_bits_follow_the_address_word() {
+    // Bits 1 and 3 stand for the first and third words after 0x1000
+    let bytes = words64(&[0x1000, 0b1011]);
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+
+    assert_eq!(decode(reader), [0x1000, 0x1008, 0x1018]);
+}
+
+#[test]
+fn consecutive_bitmaps_cover_63_words_each() {
+    let bytes = words64(&[0x1000, 1 | 1 << 63, 1 | 1 << 2, 0x3000]);
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+
+    assert_eq!(
+        decode(reader),
+        [0x1000, 0x1008 + 62 * 8, 0x1008 + 63 * 8 + 8, 0x3000]
+    );
+}
+
+#[test]
+fn elf32_bitmaps_cover_31_words_each() {
+    let words: [u32; 4] = [0x1000, 1 | 1 << 31, 1 | 1 << 1, 0x2000];
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+    let reader = Reader::new(&bytes, Class::Elf32, Endianness::Big);
+
+    assert_eq!(
+        decode(reader),
+        [0x1000, 0x1004 + 30 * 4, 0x1004 + 31 * 4, 0x2000]
+    );
+}
+
+#[test]
+fn truncated_table_is_rejected() {
+    let bytes = words64(&[0x1000]);
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+
+    assert!(relr::Table::new(reader, 0, 16).is_err());
+}
+
+#[test]
+fn relr_yields_relative_relocations_like_rela() {
+    let bytes = words64(&[0x1000, 0b11]);
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+    let table = relr::Table::new(reader, 0, bytes.len() as u64).unwrap();
+    let relocations = Relocations::new(Table::Relr(table), Machine::X86_64);
+
+    assert_eq!(relocations.len(), 2);
+    assert_eq!(relocations.relative().count(), 2);
+
+    let relocation = relocations.get(1).unwrap();
+    assert_eq!(relocation.offset, 0x1008);
+    assert_eq!(relocation.symbol, 0);
+    assert_eq!(relocation.kind, Kind::X86_64(x86_64::Type::Relative));
+    assert_eq!(relocation.addend, None);
+}
+
+#[test]
+fn android_packed_group_shares_offset_delta_and_info() {
+    let bytes = [
+        b'A', b'P', b'S', b'2', // magic
+        0x03, // count
+        0x80, 0x20, // initial offset 0x1000
+        0x03, // group of three
+        0x0b, // grouped by info and offset delta, with addends
+        0x08, // offset delta
+        0x08, // info: R_X86_64_RELATIVE
+        0x10, 0x10, 0x78, // addend deltas 16, 16, -8
+    ];
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+    let table = android::Table::new(reader, 0, bytes.len() as u64, true).unwrap();
+    let relocations = Relocations::new(Table::Android(table), Machine::X86_64);
+
+    let decoded: Vec<_> = relocations
+        .iter()
+        .map(|relocation| (relocation.offset, relocation.addend))
+        .collect();
+
+    assert_eq!(
+        decoded,
+        [
+            (0x1008, Some(0x10)),
+            (0x1010, Some(0x20)),
+            (0x1018, Some(0x18))
+        ]
+    );
+    assert!(
+        relocations
+            .iter()
+            .all(|relocation| relocation.kind.is_relative())
+    );
+}
+
+#[test]
+fn android_packed_table_needs_magic() {
+    let bytes = *b"APS1\x00";
+    let reader = Reader::new(&bytes, Class::Elf64, Endianness::Little);
+
+    assert!(android::Table::new(reader, 0, bytes.len() as u64, true).is_err());
+}
@@ -0,0 +1,208 @@
+use elf::header::{Class, Data, Machine, Type};
+use elf::note::property::x86::Feature;
+use elf::{Elf, Error};
+
+const A00_LS: &[u8] = include_bytes!("../../../data/misc/a00_ls");
+const BASIC: &[u8] = include_bytes!("../../../data/misc/basic.x86_64");
+const PHNUM: &[u8] = include_bytes!("../../../data/misc/phnum.m68k.so");
+const STRIPPED: &[u8] = include_bytes!("../../../data/misc/stripped.x86_64.so");
+const SYMVER_X86_64: &[u8] = include_bytes!("../../../data/misc/symver.x86_64.so");
+
+/// A sample with the class, byte order, machine and type its header declares
+struct Sample {
+    name: &'static str,
+    bytes: &'static [u8],
+    class: Class,
+    data: Data,
+    machine: Machine,
+    etype: Type,
+}
+
+const SAMPLES: [Sample; 11] = [
+    Sample {
+        name: "a00_ls",
+        bytes: A00_LS,
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::X86_64,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "basic.x86_64",
+        bytes: BASIC,
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::X86_64,
+        etype: Type::Exec,
+    },
+    Sample {
+        name: "phnum.m68k.so",
+        bytes: PHNUM,
+        class: Class::Elf32,
+        data: Data::Msb,
+        machine: Machine::M68k,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "stripped.x86_64.so",
+        bytes: STRIPPED,
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::X86_64,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.aarch64.so",
+        bytes: include_bytes!("../../../data/misc/symver.aarch64.so"),
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::AArch64,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.armhf.so",
+        bytes: include_bytes!("../../../data/misc/symver.armhf.so"),
+        class: Class::Elf32,
+        data: Data::Lsb,
+        machine: Machine::Arm,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.m68k.so",
+        bytes: include_bytes!("../../../data/misc/symver.m68k.so"),
+        class: Class::Elf32,
+        data: Data::Msb,
+        machine: Machine::M68k,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.powerpc64.so",
+        bytes: include_bytes!("../../../data/misc/symver.powerpc64.so"),
+        class: Class::Elf64,
+        data: Data::Msb,
+        machine: Machine::PowerPc64,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.powerpc64le.so",
+        bytes: include_bytes!("../../../data/misc/symver.powerpc64le.so"),
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::PowerPc64,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.riscv64.so",
+        bytes: include_bytes!("../../../data/misc/symver.riscv64.so"),
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::RiscV,
+        etype: Type::Dyn,
+    },
+    Sample {
+        name: "symver.x86_64.so",
+        bytes: SYMVER_X86_64,
+        class: Class::Elf64,
+        data: Data::Lsb,
+        machine: Machine::X86_64,
+        etype: Type::Dyn,
+    },
+];
+
+#[test]
+fn every_sample_parses() {
+    for sample in SAMPLES {
+        let name = sample.name;
+        let elf = Elf::parse(sample.bytes).unwrap_or_else(|error| panic!("{name}: {error:?}"));
+
+        assert_eq!(elf.header.ident.class, sample.class, "{name}");
+        assert_eq!(elf.header.ident.data, sample.data, "{name}");
+        assert_eq!(elf.header.machine, sample.machine, "{name}");
+        assert_eq!(elf.header.etype, sample.etype, "{name}");
+        assert!(elf.program_headers().is_ok(), "{name}");
+        assert!(elf.section_headers().is_ok(), "{name}");
+    }
+}
+
+#[test]
+fn damaged_samples_are_rejected_with_the_reason() {
+    let mut magic = BASIC.to_vec();
+    magic[1] = b'X';
+    assert_eq!(Elf::parse(&magic).unwrap_err(), Error::InvalidMagic);
+
+    let mut class = BASIC.to_vec();
+    class[4] = 0;
+    assert_eq!(Elf::parse(&class).unwrap_err(), Error::InvalidClass);
+
+    let mut data = BASIC.to_vec();
+    data[5] = 3;
+    assert_eq!(Elf::parse(&data).unwrap_err(), Error::InvalidData);
+
+    assert_eq!(Elf::parse(&BASIC[..40]).unwrap_err(), Error::Truncated);
+}
+
+#[test]
+fn extended_program_header_count_comes_from_section_zero() {
+    let elf = Elf::parse(PHNUM).unwrap();
+
+    assert_eq!(elf.header.phnum, 0xffff);
+    assert_eq!(elf.program_header_count(), Ok(1));
+    assert_eq!(elf.program_headers().unwrap().len(), 1);
+}
+
+#[test]
+fn stripped_objects_keep_their_dynamic_symbols() {
+    let elf = Elf::parse(STRIPPED).unwrap();
+
+    assert_eq!(elf.symbols().unwrap_err(), Error::MissingSymbolTable);
+    assert_eq!(elf.dynamic_symbols().unwrap().len(), 10);
+    assert_eq!(
+        elf.lookup("use_memset@HELLO_1.0")
+            .unwrap()
+            .map(|(index, _)| index),
+        Some(9)
+    );
+}
+
+#[test]
+fn lookup_honours_the_requested_version() {
+    let elf = Elf::parse(SYMVER_X86_64).unwrap();
+    let index = |reference| elf.lookup(reference).unwrap().map(|(index, _)| index);
+
+    assert_eq!(index("use_memset@HELLO_1.0"), Some(9));
+    assert_eq!(index("use_memset_v2@HELLO_1.42"), Some(7));
+    assert_eq!(index("use_memset@HELLO_1.42"), None);
+
+    // Neither is the default version, so `@@` finds nothing
+    assert_eq!(index("use_memset_v2@@HELLO_1.42"), None);
+}
+
+#[test]
+fn notes_give_the_build_id_and_x86_features() {
+    let elf = Elf::parse(A00_LS).unwrap();
+
+    let build_id = elf.build_id().unwrap().unwrap();
+    let hex: String = build_id.iter().map(|byte| format!("{byte:02x}")).collect();
+    assert_eq!(hex, "baba64ae8c7edfd5d7c2b72b425d45ab72a46785");
+
+    let features = elf.x86_features().unwrap().unwrap();
+    assert!(features.contains(Feature::Ibt));
+    assert!(features.contains(Feature::Shstk));
+    assert!(features.is_cet());
+
+    assert_eq!(Elf::parse(BASIC).unwrap().x86_features(), Ok(None));
+}
+
+#[test]
+fn relr_tables_unfold_into_every_relative_relocation() {
+    let elf = Elf::parse(A00_LS).unwrap();
+    let relative: Vec<_> = elf.relative_relocations().unwrap().iter().collect();
+
+    assert_eq!(relative.len(), 219);
+    assert_eq!(relative[0].offset, 0x20f10);
+    assert!(
+        relative
+            .windows(2)
+            .all(|pair| pair[0].offset < pair[1].offset)
+    );
+}
@@ -0,0 +1,236 @@
+use elf::scope::{self, Class, Definition, Reference, Scope, Tables};
+use elf::symbol::Index;
+use elf::{Elf, Error};
+
+// use_memset@HELLO_1.0 and use_memset_v2@HELLO_1.42, both non-default versions, with
+// references to memset@GLIBC_2.2.5 and to the weak __cxa_finalize@GLIBC_2.2.5.
+const SYMVER: &[u8] = include_bytes!("../../../data/misc/symver.x86_64.so");
+
+// Built from fixtures/scope.c, with `overridable` weak in the first and symbolic
+// binding in the second.
+const PLAIN: &[u8] = include_bytes!("fixtures/scope.so");
+const SYMBOLIC: &[u8] = include_bytes!("fixtures/scope-symbolic.so");
+
+// A program at a fixed address whose PLT entry for `twice` of liblink.so stands for
+// the function.
+const CANONICAL: &[u8] = include_bytes!("fixtures/canonical");
+const LIBLINK: &[u8] = include_bytes!("fixtures/liblink.so");
+
+fn parse(objects: &[&'static [u8]]) -> Vec<Elf<'static>> {
+    objects
+        .iter()
+        .map(|bytes| Elf::parse(bytes).unwrap())
+        .collect()
+}
+
+fn index(elf: &Elf, name: &str) -> usize {
+    elf.dynamic_symbols().unwrap().find(name).unwrap().0
+}
+
+/// Object and symbol index `reference` binds to
+fn lookup(scope: &[Elf], reference: &str) -> Option<(usize, usize)> {
+    scope::lookup(scope, &Reference::parse(reference), Class::Data, None)
+        .unwrap()
+        .map(|definition| (definition.object, definition.index))
+}
+
+/// Object the symbol `name` of the object at `requester` resolves to
+fn resolve(scope: &[Elf], requester: usize, name: &str, class: Class) -> Option<usize> {
+    let index = index(&scope[requester], name);
+
+    scope::resolve(scope, requester, index, class)
+        .unwrap()
+        .map(|definition| definition.object)
+}
+
+#[test]
+fn references_name_the_version_they_need() {
+    let scope = parse(&[SYMVER]);
+
+    let tables = Tables::parse(&scope[0]).unwrap();
+
+    let memset = Reference::of(&tables, index(&scope[0], "memset")).unwrap();
+    assert_eq!(memset.name, "memset");
+    assert_eq!(memset.version, Some("GLIBC_2.2.5"));
+    assert!(!memset.weak);
+
+    let finalize = Reference::of(&tables, index(&scope[0], "__cxa_finalize")).unwrap();
+    assert!(finalize.weak);
+}
+
+#[test]
+fn versioned_references_take_that_version() {
+    let scope = parse(&[SYMVER]);
+
+    assert_eq!(lookup(&scope, "use_memset@HELLO_1.0"), Some((0, 9)));
+    assert_eq!(lookup(&scope, "use_memset_v2@HELLO_1.42"), Some((0, 7)));
+    assert_eq!(lookup(&scope, "use_memset@HELLO_1.42"), None);
+    assert_eq!(lookup(&scope, "use_memset_v2@HELLO_1.0"), None);
+}
+
+#[test]
+fn unversioned_references_take_the_oldest_version_only() {
+    let scope = parse(&[SYMVER]);
+
+    // HELLO_1.0 is the first version after the base one, hidden or not
+    assert_eq!(lookup(&scope, "use_memset"), Some((0, 9)));
+    assert_eq!(lookup(&scope, "use_memset_v2"), None);
+}
+
+#[test]
+fn version_definitions_bind_as_absolute_symbols() {
+    let scope = parse(&[SYMVER]);
+    let reference = Reference::parse("HELLO_1.42@HELLO_1.42");
+
+    let definition = scope::lookup(&scope[..], &reference, Class::Data, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(definition.index, 8);
+    assert_eq!(definition.symbol.shndx, Index::Absolute);
+}
+
+#[test]
+fn undefined_symbols_are_not_definitions() {
+    let scope = parse(&[SYMVER]);
+    let memset = index(&scope[0], "memset");
+    let finalize = index(&scope[0], "__cxa_finalize");
+
+    assert_eq!(lookup(&scope, "memset"), None);
+    assert_eq!(
+        scope::resolve(&scope[..], 0, memset, Class::Plt),
+        Err(Error::UndefinedSymbol)
+    );
+    assert_eq!(
+        scope::resolve(&scope[..], 0, finalize, Class::Data),
+        Ok(None)
+    );
+}
+
+#[test]
+fn earlier_objects_interpose_on_later_ones() {
+    let scope = parse(&[SYMBOLIC, PLAIN]);
+
+    assert_eq!(resolve(&scope, 1, "shared", Class::Data), Some(0));
+    assert_eq!(resolve(&scope, 1, "shared_address", Class::Plt), Some(0));
+}
+
+#[test]
+fn weak_definitions_interpose_like_global_ones() {
+    let scope = parse(&[PLAIN, SYMBOLIC]);
+    let overridable = index(&scope[0], "overridable");
+
+    assert!(
+        scope[0]
+            .dynamic_symbols()
+            .unwrap()
+            .get(overridable)
+            .unwrap()
+            .is_weak()
+    );
+    assert_eq!(lookup(&scope, "overridable"), Some((0, overridable)));
+}
+
+#[test]
+fn symbolic_objects_bind_to_themselves_first() {
+    let scope = parse(&[PLAIN, SYMBOLIC]);
+
+    assert_eq!(resolve(&scope, 1, "shared", Class::Data), Some(1));
+    assert_eq!(resolve(&scope, 0, "shared", Class::Data), Some(0));
+}
+
+#[test]
+fn protected_definitions_are_not_preempted() {
+    let scope = parse(&[SYMBOLIC, PLAIN]);
+
+    assert_eq!(
+        resolve(&scope, 1, "protected_function", Class::Plt),
+        Some(1)
+    );
+
+    // Other objects still bind to the first definition
+    assert_eq!(
+        lookup(&scope, "protected_function").map(|(object, _)| object),
+        Some(0)
+    );
+}
+
+#[test]
+fn hidden_definitions_are_not_exported() {
+    let scope = parse(&[PLAIN, SYMBOLIC]);
+
+    assert_eq!(lookup(&scope, "hidden_function"), None);
+}
+
+#[test]
+fn copy_relocations_bind_past_the_copy() {
+    let scope = parse(&[PLAIN, SYMBOLIC]);
+    let shared = index(&scope[0], "shared");
+
+    let definition = scope::resolve(&scope[..], 0, shared, Class::Copy)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        definition,
+        Definition {
+            object: 1,
+            index: index(&scope[1], "shared"),
+            symbol: definition.symbol,
+        }
+    );
+}
+
+#[test]
+fn canonical_plt_entries_stand_for_the_function_but_in_plt_slots() {
+    let scope = parse(&[CANONICAL, LIBLINK]);
+
+    assert!(scope::exports_plt(&scope[0]));
+    assert!(!scope::exports_plt(&scope[1]));
+
+    let twice = index(&scope[0], "twice");
+    assert_eq!(lookup(&scope, "twice"), Some((0, twice)));
+    assert_eq!(resolve(&scope, 0, "twice", Class::Plt), Some(1));
+    assert_eq!(resolve(&scope, 1, "twice", Class::Data), Some(0));
+}
+
+/// A scope that parses the tables of its objects up front and has nothing to parse later
+struct Parsed(Vec<Tables<'static>>);
+
+impl Scope<'static> for Parsed {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, _: usize) -> Option<&Elf<'static>> {
+        panic!("objects are parsed again");
+    }
+
+    fn tables(&self, position: usize) -> elf::Result<Tables<'static>> {
+        self.0
+            .get(position)
+            .copied()
+            .ok_or(Error::InvalidScopeIndex)
+    }
+
+    fn exports_plt(&self, _: usize) -> bool {
+        false
+    }
+}
+
+#[test]
+fn scopes_may_parse_their_tables_once() {
+    let objects = parse(&[SYMBOLIC, PLAIN]);
+    let scope = Parsed(
+        objects
+            .iter()
+            .map(|elf| Tables::parse(elf).unwrap())
+            .collect(),
+    );
+
+    let shared = index(&objects[1], "shared");
+    let definition = scope::resolve(&scope, 1, shared, Class::Data).unwrap();
+    assert_eq!(definition.map(|definition| definition.object), Some(0));
+
+    let protected = Reference::parse("protected_function");
+    let definition = scope::lookup(&scope, &protected, Class::Plt, None).unwrap();
+    assert_eq!(definition.map(|definition| definition.object), Some(0));
+}
@@ -2,6 +2,7 @@
 
 pub mod arch;
 pub mod result;
+pub mod stderr;
 pub mod stdout;
 
 pub use result::*;
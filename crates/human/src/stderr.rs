@@ -0,0 +1,21 @@
+pub struct Stderr;
+
+fn print(msg: &str) -> crate::Result<isize> {
+    crate::arch::write(2, msg.as_ptr(), msg.len())
+}
+
+impl core::fmt::Write for Stderr {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let _ = print(s);
+        Ok(())
+    }
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {{
+        use core::fmt::Write;
+        let mut writer = $crate::stderr::Stderr;
+        let _ = write!(&mut writer, $($arg)*);
+    }};
+}
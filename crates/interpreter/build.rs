@@ -0,0 +1,35 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+// Build `xelf2` for the target it runs on, in a target directory of its own so the
+// cargo running this script keeps its lock, and hand its path to the tests.
+fn main() {
+    let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("../..");
+    let target = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("xelf2");
+
+    println!("cargo:rerun-if-changed={}", root.join("src").display());
+    println!("cargo:rerun-if-changed={}", root.join("crates").display());
+    println!("cargo:rerun-if-changed={}", root.join("build.rs").display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        root.join("linker.ld").display()
+    );
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .current_dir(&root)
+        .args(["build", "--bin", "xelf2", "--target", "x86_64-unknown-none"])
+        .arg("--target-dir")
+        .arg(&target)
+        // Flags and wrappers, such as clippy's, meant for this crate alone
+        .env_remove("CARGO_ENCODED_RUSTFLAGS")
+        .env_remove("RUSTC_WORKSPACE_WRAPPER")
+        .env_remove("CLIPPY_ARGS")
+        .status()
+        .unwrap();
+    assert!(status.success(), "building xelf2 failed");
+
+    let xelf2 = target.join("x86_64-unknown-none/debug/xelf2");
+    println!("cargo:rustc-env=XELF2={}", xelf2.display());
+}
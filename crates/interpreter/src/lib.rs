@@ -0,0 +1,3 @@
+//! End-to-end tests of `xelf2` as the interpreter of real programs, which live in
+//! `tests/`; the build script builds the `xelf2` they run.
+#![no_std]
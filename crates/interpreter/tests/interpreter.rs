@@ -0,0 +1,217 @@
+use std::fs;
+use std::os::unix::fs::{PermissionsExt, symlink};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use elf::Elf;
+use elf::program::Type;
+
+// A program and its library, built without the C library so xelf links them alone.
+const LINK: &[u8] = include_bytes!("../../elf/tests/fixtures/link");
+const LIBLINK: &[u8] = include_bytes!("../../elf/tests/fixtures/liblink.so");
+
+// A program and a library that each call through one PLT slot, the program built
+// lazy and with `DF_BIND_NOW`. They exit with 1 and 2 when the program's slot and the
+// library's were bound before their first call, and 4 and 8 when still unbound after.
+const LAZY: &[u8] = include_bytes!("../../elf/tests/fixtures/lazy");
+const LAZY_NOW: &[u8] = include_bytes!("../../elf/tests/fixtures/lazy-now");
+const LIBLAZY: &[u8] = include_bytes!("../../elf/tests/fixtures/liblazy.so");
+
+// A glibc program and a library reaching thread-local variables through each TLS
+// relocation type. The program exits with a bit set for each check that failed.
+const TLS: &[u8] = include_bytes!("../../elf/tests/fixtures/tls");
+const LIBTLS: &[u8] = include_bytes!("../../elf/tests/fixtures/libtls.so");
+
+// A library of another machine, which the search passes over.
+const FOREIGN: &[u8] = include_bytes!("../../../data/misc/symver.aarch64.so");
+
+// A glibc program, which needs libc.so.6 and, through it, glibc's dynamic linker.
+const GLIBC: &[u8] = include_bytes!("../../../data/pf_c/main_dynamic");
+
+/// What the programs name as their interpreter, relative to the directory they run in
+const INTERPRETER: &str = "xelf2";
+
+/// The `xelf2` binary the build script builds for these tests
+fn xelf2() -> PathBuf {
+    PathBuf::from(env!("XELF2"))
+}
+
+/// A directory of its own for each test, holding its objects and a link to `xelf2`
+struct Sandbox {
+    directory: PathBuf,
+}
+
+impl Sandbox {
+    fn new(test: &str) -> Self {
+        let directory = std::env::temp_dir().join(format!("xelf-{}-{test}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        symlink(xelf2(), directory.join(INTERPRETER)).unwrap();
+
+        Self { directory }
+    }
+
+    /// Write an object into the sandbox, pointing its `PT_INTERP` at `xelf2` if it has one
+    fn install(&self, name: &str, bytes: &[u8]) -> PathBuf {
+        let mut bytes = bytes.to_vec();
+
+        let interp = Elf::parse(&bytes)
+            .unwrap()
+            .program_headers()
+            .unwrap()
+            .find(Type::Interp);
+        if let Some(interp) = interp {
+            let field = &mut bytes[interp.offset as usize..][..interp.filesz as usize];
+            assert!(INTERPRETER.len() < field.len());
+            field.fill(0);
+            field[..INTERPRETER.len()].copy_from_slice(INTERPRETER.as_bytes());
+        }
+
+        let path = self.directory.join(name);
+        fs::write(&path, bytes).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    /// Run a program of the sandbox from within it, so the kernel finds `xelf2`
+    fn run(&self, program: &Path, environment: &[(&str, &str)]) -> Output {
+        Command::new(program)
+            .current_dir(&self.directory)
+            .env_remove("LD_LIBRARY_PATH")
+            .env_remove("LD_BIND_NOW")
+            .env_remove("XELF_DEBUG")
+            .envs(environment.iter().copied())
+            .output()
+            .unwrap()
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+#[test]
+fn programs_without_glibc_are_linked_by_xelf() {
+    let sandbox = Sandbox::new("link");
+    sandbox.install("liblink.so", LIBLINK);
+    let program = sandbox.install("link", LINK);
+
+    // Both constructors ran, and the library's data and function were reached, after
+    // the search was logged on standard output
+    let output = sandbox.run(&program, &[("XELF_DEBUG", "1")]);
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert!(output.stdout.ends_with(b"linked\n"), "{output:?}");
+}
+
+#[test]
+fn search_misses_are_silent() {
+    let sandbox = Sandbox::new("silent");
+    sandbox.install("liblink.so", LIBLINK);
+    let program = sandbox.install("link", LINK);
+
+    // Nothing is found in the first directory, nor in the second, which exists
+    let output = sandbox.run(&program, &[("LD_LIBRARY_PATH", "/nonexistent:/usr/lib")]);
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert_eq!(output.stdout, b"linked\n");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn missing_libraries_stop_the_program() {
+    let sandbox = Sandbox::new("missing");
+    let program = sandbox.install("link", LINK);
+
+    let output = sandbox.run(&program, &[]);
+    assert_eq!(output.status.code(), Some(127));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("liblink.so: not found"));
+}
+
+#[test]
+fn libraries_of_another_machine_are_passed_over() {
+    let sandbox = Sandbox::new("foreign");
+    sandbox.install("liblink.so", LIBLINK);
+    let program = sandbox.install("link", LINK);
+
+    // `LD_LIBRARY_PATH` comes before the program's `DT_RUNPATH` of `$ORIGIN`
+    let foreign = sandbox.directory.join("foreign");
+    fs::create_dir(&foreign).unwrap();
+    fs::write(foreign.join("liblink.so"), FOREIGN).unwrap();
+
+    let output = sandbox.run(
+        &program,
+        &[
+            ("LD_LIBRARY_PATH", foreign.to_str().unwrap()),
+            ("XELF_DEBUG", "1"),
+        ],
+    );
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert!(output.stderr.is_empty(), "{output:?}");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("skipping"), "{stdout}");
+    assert!(stdout.ends_with("linked\n"), "{stdout}");
+}
+
+/// Exit status of a lazy binding program run with `environment` in a sandbox of its own
+fn lazy(test: &str, program: &[u8], environment: &[(&str, &str)]) -> Option<i32> {
+    let sandbox = Sandbox::new(test);
+    sandbox.install("liblink.so", LIBLINK);
+    sandbox.install("liblazy.so", LIBLAZY);
+    let program = sandbox.install("lazy", program);
+
+    let output = sandbox.run(&program, environment);
+    assert!(output.stderr.is_empty(), "{output:?}");
+    output.status.code()
+}
+
+#[test]
+fn plt_slots_are_bound_on_their_first_call() {
+    assert_eq!(lazy("lazy", LAZY, &[]), Some(0));
+    assert_eq!(lazy("lazy-empty", LAZY, &[("LD_BIND_NOW", "")]), Some(0));
+}
+
+#[test]
+fn bind_now_binds_every_slot_before_start() {
+    assert_eq!(lazy("bind-now", LAZY, &[("LD_BIND_NOW", "1")]), Some(1 | 2));
+}
+
+#[test]
+fn objects_linked_with_bind_now_have_their_own_slots_bound() {
+    assert_eq!(lazy("lazy-now", LAZY_NOW, &[]), Some(1));
+}
+
+// glibc's dynamic linker is loaded as one more library, and its private state filled
+// in by xelf before libc starts the program.
+#[test]
+fn glibc_programs_are_linked_by_xelf() {
+    if !Path::new("/lib64/ld-linux-x86-64.so.2").exists() {
+        return;
+    }
+
+    let sandbox = Sandbox::new("glibc");
+    let program = sandbox.install("main_dynamic", GLIBC);
+
+    let output = sandbox.run(&program, &[]);
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert_eq!(output.stdout, b"\nHM\n", "{output:?}");
+    assert!(output.stderr.is_empty(), "{output:?}");
+}
+
+#[test]
+fn thread_local_variables_are_reached_from_programs_and_libraries() {
+    if !Path::new("/lib64/ld-linux-x86-64.so.2").exists() {
+        return;
+    }
+
+    let sandbox = Sandbox::new("tls");
+    sandbox.install("libtls.so", LIBTLS);
+    let program = sandbox.install("tls", TLS);
+
+    for environment in [&[][..], &[("LD_BIND_NOW", "1")]] {
+        let output = sandbox.run(&program, environment);
+        assert_eq!(output.status.code(), Some(0), "{output:?}");
+    }
+}
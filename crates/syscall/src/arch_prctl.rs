@@ -0,0 +1,36 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::ArchPrctl as usize;
+
+/// Set the base of the `fs` segment, the thread pointer on x86-64
+pub const ARCH_SET_FS: usize = 0x1002;
+/// Read the base of the `fs` segment into the word at the address given
+pub const ARCH_GET_FS: usize = 0x1003;
+
+define_syscall_error!(
+    Error,
+    ArchPrctl,
+    "arch_prctl",
+    [
+        [
+            BadAddress,
+            -14,
+            "Word to read into outside the address space",
+            EFAULT
+        ],
+        [InvalidArgument, -22, "Unknown code", EINVAL],
+        [
+            PermissionDenied,
+            -1,
+            "Base outside the user address space",
+            EPERM
+        ]
+    ]
+);
+
+pub fn arch_prctl(code: usize, address: usize) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall2(NUMBER, code, address);
+
+    handle_result(syscall_result)
+}
@@ -0,0 +1,23 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Close as usize;
+
+define_syscall_error!(
+    Error,
+    Close,
+    "close",
+    [
+        [BadFileDescriptor, -9, "Bad file descriptor", EBADF],
+        [Interrupted, -4, "System call was interrupted", EINTR],
+        [IOError, -5, "Input/output error", EIO],
+        [NoSpaceLeft, -28, "No space left on device", ENOSPC],
+        [QuotaExceeded, -122, "Disk quota exceeded", EDQUOT]
+    ]
+);
+
+pub fn close(file_descriptor: isize) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall1(NUMBER, file_descriptor as usize);
+
+    handle_result(syscall_result)
+}
@@ -0,0 +1,128 @@
+pub mod path;
+
+pub use path::Path;
+
+use crate::mmap::{Address, MapFlags};
+use crate::open::flags::AtFlag;
+use crate::result::{Error, Result};
+use crate::{Stat, close, fstat, mmap, munmap, openat, pread64};
+
+/// A file descriptor closed when dropped
+#[derive(Debug)]
+pub struct OwnedFd(isize);
+
+impl OwnedFd {
+    /// Take ownership of a descriptor nothing else will close
+    pub fn from_raw(file_descriptor: isize) -> Self {
+        Self(file_descriptor)
+    }
+
+    pub fn as_raw(&self) -> isize {
+        self.0
+    }
+
+    /// Give the descriptor back without closing it
+    pub fn into_raw(self) -> isize {
+        let file_descriptor = self.0;
+        core::mem::forget(self);
+        file_descriptor
+    }
+}
+
+impl Drop for OwnedFd {
+    fn drop(&mut self) {
+        let _ = close(self.0);
+    }
+}
+
+/// A private mapping of a whole file, unmapped when dropped
+#[derive(Debug)]
+pub struct Mapping {
+    pub address: Address,
+    pub length: usize,
+}
+
+impl Mapping {
+    pub fn as_ptr(&self) -> *const u8 {
+        self.address.as_ptr()
+    }
+
+    /// The mapped bytes, which must have been mapped readable
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.length) }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        let _ = munmap(self.address, self.length);
+    }
+}
+
+/// An open file, read at explicit offsets
+#[derive(Debug)]
+pub struct File {
+    pub fd: OwnedFd,
+}
+
+impl File {
+    /// Open a path relative to the current directory, given as `&CStr` or `&str`
+    pub fn open<P: Path + ?Sized>(path: &P, flags: usize) -> Result<Self> {
+        path.with_cstr(|path| {
+            let file_descriptor = openat(AtFlag::FDCWD as i32, path.as_ptr().cast(), flags as i32)?;
+
+            Ok(Self {
+                fd: OwnedFd::from_raw(file_descriptor),
+            })
+        })
+    }
+
+    /// Fill `buffer` from `offset`, failing if the file ends first
+    pub fn read_exact_at(&self, buffer: &mut [u8], offset: usize) -> Result<()> {
+        let mut filled = 0;
+
+        while filled < buffer.len() {
+            let remaining = &mut buffer[filled..];
+            let count = pread64(
+                self.fd.as_raw(),
+                remaining.as_mut_ptr(),
+                remaining.len(),
+                offset + filled,
+            )?;
+
+            if count == 0 {
+                return Err(Error::UnexpectedEof);
+            }
+
+            filled += count as usize;
+        }
+
+        Ok(())
+    }
+
+    pub fn stat(&self) -> Result<Stat> {
+        let mut stat = Stat::default();
+        fstat(self.fd.as_raw(), &mut stat)?;
+        Ok(stat)
+    }
+
+    /// Size of the file in bytes
+    pub fn size(&self) -> Result<usize> {
+        Ok(self.stat()?.size as usize)
+    }
+
+    /// Map the whole file privately with the given `Prot` bits
+    pub fn map(&self, protection: usize) -> Result<Mapping> {
+        let length = self.size()?;
+        let address = mmap(
+            0,
+            length,
+            protection,
+            MapFlags::Private.into(),
+            self.fd.as_raw(),
+            0,
+        )?;
+
+        Ok(Mapping { address, length })
+    }
+}
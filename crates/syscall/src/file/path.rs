@@ -0,0 +1,38 @@
+use core::ffi::CStr;
+
+use crate::open::Error as OpenError;
+use crate::result::{Error, Result};
+
+/// Longest path the kernel accepts, terminating NUL included
+pub const PATH_MAX: usize = 4096;
+
+/// A path that can be handed to the kernel NUL-terminated.
+pub trait Path {
+    /// Call `f` with the path as a C string
+    fn with_cstr<T>(&self, f: impl FnOnce(&CStr) -> Result<T>) -> Result<T>;
+}
+
+impl Path for CStr {
+    fn with_cstr<T>(&self, f: impl FnOnce(&CStr) -> Result<T>) -> Result<T> {
+        f(self)
+    }
+}
+
+/// Rust strings are copied into a stack buffer and terminated there
+impl Path for str {
+    fn with_cstr<T>(&self, f: impl FnOnce(&CStr) -> Result<T>) -> Result<T> {
+        let mut buffer = [0u8; PATH_MAX];
+
+        if self.len() >= PATH_MAX {
+            return Err(Error::Open(OpenError::PathnameTooLong));
+        }
+
+        buffer[..self.len()].copy_from_slice(self.as_bytes());
+
+        // An interior NUL would silently cut the path short
+        let path = CStr::from_bytes_with_nul(&buffer[..=self.len()])
+            .map_err(|_| Error::Open(OpenError::InvalidPath))?;
+
+        f(path)
+    }
+}
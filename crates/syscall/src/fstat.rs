@@ -0,0 +1,67 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Fstat as usize;
+
+define_syscall_error!(
+    Error,
+    Fstat,
+    "fstat",
+    [
+        [BadFileDescriptor, -9, "Bad file descriptor", EBADF],
+        [InvalidBuffer, -14, "Invalid buffer pointer", EFAULT],
+        [OutOfMemory, -12, "Out of kernel memory", ENOMEM],
+        [
+            Overflow,
+            -75,
+            "File size does not fit the structure",
+            EOVERFLOW
+        ]
+    ]
+);
+
+pub const S_IFMT: u32 = 0o170000;
+pub const S_IFDIR: u32 = 0o040000;
+pub const S_IFREG: u32 = 0o100000;
+pub const S_IFLNK: u32 = 0o120000;
+
+/// The x86_64 `struct stat`
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Stat {
+    pub dev: u64,        /* ID of device containing file */
+    pub ino: u64,        /* Inode number */
+    pub nlink: u64,      /* Number of hard links */
+    pub mode: u32,       /* File type and mode */
+    pub uid: u32,        /* User ID of owner */
+    pub gid: u32,        /* Group ID of owner */
+    pub pad0: u32,       /* Padding */
+    pub rdev: u64,       /* Device ID (if special file) */
+    pub size: i64,       /* Total size, in bytes */
+    pub blksize: i64,    /* Block size for filesystem I/O */
+    pub blocks: i64,     /* Number of 512 B blocks allocated */
+    pub atime: i64,      /* Time of last access */
+    pub atime_nsec: i64, /* Nanoseconds of last access */
+    pub mtime: i64,      /* Time of last modification */
+    pub mtime_nsec: i64, /* Nanoseconds of last modification */
+    pub ctime: i64,      /* Time of last status change */
+    pub ctime_nsec: i64, /* Nanoseconds of last status change */
+    pub unused: [i64; 3],
+}
+
+impl Stat {
+    pub fn is_regular(&self) -> bool {
+        self.mode & S_IFMT == S_IFREG
+    }
+
+    pub fn is_directory(&self) -> bool {
+        self.mode & S_IFMT == S_IFDIR
+    }
+}
+
+pub fn fstat(file_descriptor: isize, stat: &mut Stat) -> crate::result::Result<isize> {
+    let syscall_result =
+        Arch::syscall2(NUMBER, file_descriptor as usize, stat as *mut Stat as usize);
+
+    handle_result(syscall_result)
+}
@@ -0,0 +1,30 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::GetRandom as usize;
+
+/// Draw from the urandom source, failing instead of blocking when no entropy is ready
+pub const GRND_NONBLOCK: u32 = 0x1;
+/// Draw from the random source
+pub const GRND_RANDOM: u32 = 0x2;
+/// Return whatever is available even before the pool is initialised
+pub const GRND_INSECURE: u32 = 0x4;
+
+define_syscall_error!(
+    Error,
+    GetRandom,
+    "getrandom",
+    [
+        [Again, -11, "Entropy pool not initialised yet", EAGAIN],
+        [BadAddress, -14, "Buffer outside the address space", EFAULT],
+        [Interrupted, -4, "System call was interrupted", EINTR],
+        [InvalidArgument, -22, "Invalid flags", EINVAL],
+        [NotImplemented, -38, "Kernel lacks getrandom", ENOSYS]
+    ]
+);
+
+pub fn getrandom(buffer: *mut u8, length: usize, flags: u32) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall3(NUMBER, buffer as usize, length, flags as usize);
+
+    handle_result(syscall_result)
+}
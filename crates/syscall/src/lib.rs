@@ -5,14 +5,40 @@ pub use result::*;
 #[macro_use]
 pub mod macros;
 
+pub mod arch_prctl;
+pub mod close;
 pub mod exit;
+pub mod file;
+pub mod fstat;
+pub mod getrandom;
+pub mod lseek;
+pub mod mmap;
+pub mod mprotect;
+pub mod munmap;
 pub mod open;
+pub mod pread64;
 pub mod read;
+pub mod readlink;
+pub mod set_tid_address;
+pub mod statx;
 pub mod write;
 
+pub use arch_prctl::arch_prctl;
+pub use close::close;
 pub use exit::exit;
+pub use file::{File, OwnedFd};
+pub use fstat::{Stat, fstat};
+pub use getrandom::getrandom;
+pub use lseek::{Whence, lseek};
+pub use mmap::mmap;
+pub use mprotect::mprotect;
+pub use munmap::munmap;
 pub use open::{openat, openat4};
+pub use pread64::pread64;
 pub use read::read;
+pub use readlink::{readlink, readlinkat};
+pub use set_tid_address::set_tid_address;
+pub use statx::{Statx, statx};
 pub use write::write;
 
 pub enum Number {
@@ -20,6 +46,18 @@ pub enum Number {
     Write = 1,
     OpenAt = 257,
     Read = 0,
+    Mmap = 9,
+    Mprotect = 10,
+    Munmap = 11,
+    Close = 3,
+    Fstat = 5,
+    Lseek = 8,
+    Pread64 = 17,
+    Statx = 332,
+    GetRandom = 318,
+    ReadLinkAt = 267,
+    ArchPrctl = 158,
+    SetTidAddress = 218,
 }
 
 impl Number {
@@ -28,6 +66,18 @@ impl Number {
             60 => Some(Number::Exit),
             1 => Some(Number::Write),
             257 => Some(Number::OpenAt),
+            9 => Some(Number::Mmap),
+            10 => Some(Number::Mprotect),
+            11 => Some(Number::Munmap),
+            3 => Some(Number::Close),
+            5 => Some(Number::Fstat),
+            8 => Some(Number::Lseek),
+            17 => Some(Number::Pread64),
+            332 => Some(Number::Statx),
+            318 => Some(Number::GetRandom),
+            267 => Some(Number::ReadLinkAt),
+            158 => Some(Number::ArchPrctl),
+            218 => Some(Number::SetTidAddress),
             _ => None,
         }
     }
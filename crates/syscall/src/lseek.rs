@@ -0,0 +1,59 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Lseek as usize;
+
+define_syscall_error!(
+    Error,
+    Lseek,
+    "lseek",
+    [
+        [BadFileDescriptor, -9, "Bad file descriptor", EBADF],
+        [
+            InvalidArgument,
+            -22,
+            "Invalid whence or resulting offset",
+            EINVAL
+        ],
+        [NoData, -6, "No data or hole past the offset", ENXIO],
+        [Overflow, -75, "Resulting offset does not fit", EOVERFLOW],
+        [
+            NotSeekable,
+            -29,
+            "Descriptor is a pipe, socket or FIFO",
+            ESPIPE
+        ]
+    ]
+);
+
+#[repr(isize)]
+#[derive(Clone, Copy)]
+pub enum Whence {
+    Set = 0,  // Offset from the start of the file
+    Cur = 1,  // Offset from the current position
+    End = 2,  // Offset from the end of the file
+    Data = 3, // Next data at or after the offset
+    Hole = 4, // Next hole at or after the offset
+}
+
+impl From<Whence> for usize {
+    fn from(whence: Whence) -> usize {
+        whence as usize
+    }
+}
+
+/// Move the file position, returning the new offset from the start of the file
+pub fn lseek(
+    file_descriptor: isize,
+    offset: isize,
+    whence: Whence,
+) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall3(
+        NUMBER,
+        file_descriptor as usize,
+        offset as usize,
+        whence.into(),
+    );
+
+    handle_result(syscall_result)
+}
@@ -79,10 +79,8 @@ macro_rules! define_syscall_error {
                 Err(err) => {
                     let errno : isize = err.into();
 
-                    human::info!("\nRaw syscall error: {} = {}\n",
-                              SYSCALL_NAME, errno);
-
-                    // let abs_errno = if errno < 0 { -errno } else { errno };
+                    // The tables list errno values negated, as the kernel returns them
+                    let errno = if errno > 0 { -errno } else { errno };
 
                     // Create appropriate error based on the error code
                     let matched_error = match errno {
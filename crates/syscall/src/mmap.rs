@@ -0,0 +1,100 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+pub mod flags;
+
+pub use flags::{MapFlags, Prot};
+
+static NUMBER: usize = Number::Mmap as usize;
+
+define_syscall_error!(
+    Error,
+    Mmap,
+    "mmap",
+    [
+        [BadFileDescriptor, -9, "Bad file descriptor", EBADF],
+        [
+            PermissionDenied,
+            -13,
+            "File not open for the requested access",
+            EACCES
+        ],
+        [Again, -11, "File locked or too much memory locked", EAGAIN],
+        [
+            InvalidArgument,
+            -22,
+            "Invalid address, length, offset or flags",
+            EINVAL
+        ],
+        [
+            NoDevice,
+            -19,
+            "Filesystem does not support memory mapping",
+            ENODEV
+        ],
+        [
+            OutOfMemory,
+            -12,
+            "No memory available or mapping limit reached",
+            ENOMEM
+        ],
+        [
+            Overflow,
+            -75,
+            "Offset overflows the address space",
+            EOVERFLOW
+        ],
+        [NotPermitted, -1, "Operation not permitted", EPERM],
+        [
+            TextBusy,
+            -26,
+            "File open for writing with MAP_DENYWRITE",
+            ETXTBSY
+        ],
+        [
+            FileExists,
+            -17,
+            "Range already mapped with MAP_FIXED_NOREPLACE",
+            EEXIST
+        ]
+    ]
+);
+
+/// Start of a mapping, as returned by the kernel
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Address(pub usize);
+
+impl Address {
+    pub fn to(self) -> usize {
+        self.0
+    }
+
+    pub fn as_ptr<T>(self) -> *mut T {
+        self.0 as *mut T
+    }
+
+    pub fn offset(self, offset: usize) -> Self {
+        Self(self.0 + offset)
+    }
+}
+
+pub fn mmap(
+    address: usize,
+    length: usize,
+    protection: usize,
+    flags: usize,
+    file_descriptor: isize,
+    offset: usize,
+) -> crate::result::Result<Address> {
+    let syscall_result = Arch::syscall6(
+        NUMBER,
+        address,
+        length,
+        protection,
+        flags,
+        file_descriptor as usize,
+        offset,
+    );
+
+    handle_result(syscall_result).map(|address| Address(address as usize))
+}
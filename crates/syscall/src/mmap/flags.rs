@@ -0,0 +1,76 @@
+#[repr(isize)]
+#[derive(Clone, Copy)]
+pub enum Prot {
+    None = 0x0,  // Pages may not be accessed
+    Read = 0x1,  // Pages may be read
+    Write = 0x2, // Pages may be written
+    Exec = 0x4,  // Pages may be executed
+}
+
+impl From<Prot> for usize {
+    fn from(flag: Prot) -> usize {
+        flag as usize
+    }
+}
+
+// Prot | Prot
+impl core::ops::BitOr for Prot {
+    type Output = usize;
+    fn bitor(self, rhs: Self) -> usize {
+        (self as usize) | (rhs as usize)
+    }
+}
+
+// usize | Prot
+impl core::ops::BitOr<Prot> for usize {
+    type Output = usize;
+    fn bitor(self, rhs: Prot) -> usize {
+        self | (rhs as usize)
+    }
+}
+
+#[repr(isize)]
+#[derive(Clone, Copy)]
+pub enum MapFlags {
+    // Sharing type, exactly one is required
+    Shared = 0x01,
+    Private = 0x02,
+    SharedValidate = 0x03,
+
+    // Placement and behaviour
+    Fixed = 0x10,
+    Anonymous = 0x20,
+    GrowsDown = 0x0100,
+    DenyWrite = 0x0800,
+    Executable = 0x1000,
+    Locked = 0x2000,
+    NoReserve = 0x4000,
+    Populate = 0x8000,
+    NonBlock = 0x10000,
+    Stack = 0x20000,
+    HugeTlb = 0x40000,
+    Sync = 0x80000,
+    FixedNoReplace = 0x100000,
+}
+
+impl From<MapFlags> for usize {
+    fn from(flag: MapFlags) -> usize {
+        flag as usize
+    }
+}
+
+// MapFlags | MapFlags
+impl core::ops::BitOr for MapFlags {
+    type Output = usize;
+    fn bitor(self, rhs: Self) -> usize {
+        (self as usize) | (rhs as usize)
+    }
+}
+
+// usize | MapFlags
+impl core::ops::BitOr<MapFlags> for usize {
+    type Output = usize;
+    fn bitor(self, rhs: MapFlags) -> usize {
+        self | (rhs as usize)
+    }
+}
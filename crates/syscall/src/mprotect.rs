@@ -0,0 +1,41 @@
+use super::Number;
+use crate::mmap::Address;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Mprotect as usize;
+
+define_syscall_error!(
+    Error,
+    Mprotect,
+    "mprotect",
+    [
+        [
+            PermissionDenied,
+            -13,
+            "Protection not allowed for the mapped file",
+            EACCES
+        ],
+        [
+            InvalidArgument,
+            -22,
+            "Address not page aligned or invalid protection",
+            EINVAL
+        ],
+        [
+            OutOfMemory,
+            -12,
+            "Range not fully mapped or mapping limit reached",
+            ENOMEM
+        ]
+    ]
+);
+
+pub fn mprotect(
+    address: Address,
+    length: usize,
+    protection: usize,
+) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall3(NUMBER, address.to(), length, protection);
+
+    handle_result(syscall_result)
+}
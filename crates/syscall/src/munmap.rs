@@ -0,0 +1,31 @@
+use super::Number;
+use crate::mmap::Address;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Munmap as usize;
+
+define_syscall_error!(
+    Error,
+    Munmap,
+    "munmap",
+    [
+        [
+            InvalidArgument,
+            -22,
+            "Address not page aligned or length is zero",
+            EINVAL
+        ],
+        [
+            OutOfMemory,
+            -12,
+            "Unmapping would exceed the mapping limit",
+            ENOMEM
+        ]
+    ]
+);
+
+pub fn munmap(address: Address, length: usize) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall2(NUMBER, address.to(), length);
+
+    handle_result(syscall_result)
+}
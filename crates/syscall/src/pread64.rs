@@ -0,0 +1,48 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Pread64 as usize;
+
+define_syscall_error!(
+    Error,
+    Pread64,
+    "pread64",
+    [
+        [BadFileDescriptor, -9, "Bad file descriptor", EBADF],
+        [InvalidBuffer, -14, "Invalid buffer pointer", EFAULT],
+        [
+            InvalidArgument,
+            -22,
+            "Invalid count or negative offset",
+            EINVAL
+        ],
+        [Interrupted, -4, "System call was interrupted", EINTR],
+        [IOError, -5, "Input/output error", EIO],
+        [IsDirectory, -21, "Is a directory", EISDIR],
+        [Overflow, -75, "Offset overflows the file size", EOVERFLOW],
+        [
+            NotSeekable,
+            -29,
+            "Descriptor is a pipe, socket or FIFO",
+            ESPIPE
+        ]
+    ]
+);
+
+/// Read at an offset of the file, leaving the file position untouched
+pub fn pread64(
+    file_descriptor: isize,
+    byte_buffer: *mut u8,
+    byte_length: usize,
+    offset: usize,
+) -> crate::result::Result<isize> {
+    let arch_result = Arch::syscall4(
+        NUMBER,
+        file_descriptor as usize,
+        byte_buffer as usize,
+        byte_length,
+        offset,
+    );
+
+    handle_result(arch_result)
+}
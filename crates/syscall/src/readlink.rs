@@ -0,0 +1,64 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+use crate::file::path::Path;
+use crate::open::flags::AtFlag;
+
+static NUMBER: usize = Number::ReadLinkAt as usize;
+
+define_syscall_error!(
+    Error,
+    ReadLink,
+    "readlinkat",
+    [
+        [FileNotFound, -2, "File not found", ENOENT],
+        [PermissionDenied, -13, "Permission denied", EACCES],
+        [InvalidBuffer, -14, "Invalid buffer pointer", EFAULT],
+        [NotALink, -22, "Not a symbolic link", EINVAL],
+        [IOError, -5, "Input/output error", EIO],
+        [
+            TooManySymlinks,
+            -40,
+            "Too many levels of symbolic links",
+            ELOOP
+        ],
+        [PathnameTooLong, -36, "Pathname too long", ENAMETOOLONG],
+        [DirectoryNotFound, -20, "Directory not found", ENOTDIR]
+    ]
+);
+
+/// Read the target of a symbolic link, which the kernel does not terminate
+pub fn readlinkat(
+    directory_file_descriptor: i32,
+    file_pathname: *const u8,
+    byte_buffer: *mut u8,
+    byte_count: usize,
+) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall4(
+        NUMBER,
+        directory_file_descriptor as usize,
+        file_pathname as usize,
+        byte_buffer as usize,
+        byte_count,
+    );
+
+    handle_result(syscall_result)
+}
+
+/// Target of the symbolic link at `path`, relative to the current directory, as the
+/// leading bytes of `buffer`
+pub fn readlink<'b, P: Path + ?Sized>(
+    path: &P,
+    buffer: &'b mut [u8],
+) -> crate::result::Result<&'b [u8]> {
+    let length = path.with_cstr(|path| {
+        readlinkat(
+            AtFlag::FDCWD as i32,
+            path.as_ptr().cast(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+        )
+    })?;
+
+    Ok(&buffer[..length as usize])
+}
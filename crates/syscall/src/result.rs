@@ -1,7 +1,19 @@
 use result::ErrorTrait;
 
+use crate::arch_prctl::Error as ArchPrctlError;
+use crate::close::Error as CloseError;
+use crate::fstat::Error as FstatError;
+use crate::getrandom::Error as GetRandomError;
+use crate::lseek::Error as LseekError;
+use crate::mmap::Error as MmapError;
+use crate::mprotect::Error as MprotectError;
+use crate::munmap::Error as MunmapError;
 use crate::open::Error as OpenError;
+use crate::pread64::Error as Pread64Error;
 use crate::read::Error as ReadError;
+use crate::readlink::Error as ReadLinkError;
+use crate::set_tid_address::Error as SetTidAddressError;
+use crate::statx::Error as StatxError;
 use crate::write::Error as WriteError;
 
 #[repr(isize)]
@@ -10,6 +22,19 @@ pub enum Error {
     Open(OpenError),
     Read(ReadError),
     Write(WriteError),
+    Mmap(MmapError),
+    Munmap(MunmapError),
+    Mprotect(MprotectError),
+    Close(CloseError),
+    Lseek(LseekError),
+    Pread64(Pread64Error),
+    Fstat(FstatError),
+    Statx(StatxError),
+    GetRandom(GetRandomError),
+    ReadLink(ReadLinkError),
+    ArchPrctl(ArchPrctlError),
+    SetTidAddress(SetTidAddressError),
+    UnexpectedEof,
     TODO,
 }
 
@@ -25,6 +50,19 @@ impl ErrorTrait for Error {
             Error::Open(err) => err.describe(),
             Error::Read(err) => err.describe(),
             Error::Write(err) => err.describe(),
+            Error::Mmap(err) => err.describe(),
+            Error::Munmap(err) => err.describe(),
+            Error::Mprotect(err) => err.describe(),
+            Error::Close(err) => err.describe(),
+            Error::Lseek(err) => err.describe(),
+            Error::Pread64(err) => err.describe(),
+            Error::Fstat(err) => err.describe(),
+            Error::Statx(err) => err.describe(),
+            Error::GetRandom(err) => err.describe(),
+            Error::ReadLink(err) => err.describe(),
+            Error::ArchPrctl(err) => err.describe(),
+            Error::SetTidAddress(err) => err.describe(),
+            Error::UnexpectedEof => "File ended before the buffer was filled",
             Error::TODO => "TODO",
         }
     }
@@ -34,6 +72,19 @@ impl ErrorTrait for Error {
             Error::Open(err) => err.advert(),
             Error::Read(err) => err.advert(),
             Error::Write(err) => err.advert(),
+            Error::Mmap(err) => err.advert(),
+            Error::Munmap(err) => err.advert(),
+            Error::Mprotect(err) => err.advert(),
+            Error::Close(err) => err.advert(),
+            Error::Lseek(err) => err.advert(),
+            Error::Pread64(err) => err.advert(),
+            Error::Fstat(err) => err.advert(),
+            Error::Statx(err) => err.advert(),
+            Error::GetRandom(err) => err.advert(),
+            Error::ReadLink(err) => err.advert(),
+            Error::ArchPrctl(err) => err.advert(),
+            Error::SetTidAddress(err) => err.advert(),
+            Error::UnexpectedEof => None,
             Error::TODO => None,
         }
     }
@@ -0,0 +1,14 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::SetTidAddress as usize;
+
+define_syscall_error!(Error, SetTidAddress, "set_tid_address", []);
+
+/// Have the kernel clear the word at `address` when the thread exits, and return the
+/// thread id
+pub fn set_tid_address(address: *mut i32) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall1(NUMBER, address as usize);
+
+    handle_result(syscall_result)
+}
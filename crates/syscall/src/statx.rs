@@ -0,0 +1,91 @@
+use super::Number;
+use arch::{Arch, Callable};
+
+static NUMBER: usize = Number::Statx as usize;
+
+define_syscall_error!(
+    Error,
+    Statx,
+    "statx",
+    [
+        [PermissionDenied, -13, "Search permission denied", EACCES],
+        [BadFileDescriptor, -9, "Bad file descriptor", EBADF],
+        [InvalidBuffer, -14, "Invalid path or buffer pointer", EFAULT],
+        [InvalidArgument, -22, "Invalid flags or mask", EINVAL],
+        [
+            TooManySymlinks,
+            -40,
+            "Too many levels of symbolic links",
+            ELOOP
+        ],
+        [PathnameTooLong, -36, "Pathname too long", ENAMETOOLONG],
+        [FileNotFound, -2, "File not found", ENOENT],
+        [OutOfMemory, -12, "Out of kernel memory", ENOMEM],
+        [DirectoryNotFound, -20, "Directory not found", ENOTDIR]
+    ]
+);
+
+pub const STATX_TYPE: u32 = 0x0001;
+pub const STATX_MODE: u32 = 0x0002;
+pub const STATX_SIZE: u32 = 0x0200;
+pub const STATX_BASIC_STATS: u32 = 0x07ff;
+
+/// `AT_EMPTY_PATH`: describe the directory descriptor itself when the path is empty
+pub const AT_EMPTY_PATH: usize = 0x1000;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Timestamp {
+    pub sec: i64,  /* Seconds since the Epoch */
+    pub nsec: u32, /* Nanoseconds since tv_sec */
+    pub reserved: i32,
+}
+
+/// The `struct statx` filled by the kernel, valid as far as `mask` says
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Statx {
+    pub mask: u32,       /* Mask of bits indicating filled fields */
+    pub blksize: u32,    /* Block size for filesystem I/O */
+    pub attributes: u64, /* Extra file attribute indicators */
+    pub nlink: u32,      /* Number of hard links */
+    pub uid: u32,        /* User ID of owner */
+    pub gid: u32,        /* Group ID of owner */
+    pub mode: u16,       /* File type and mode */
+    pub spare0: u16,
+    pub ino: u64,              /* Inode number */
+    pub size: u64,             /* Total size in bytes */
+    pub blocks: u64,           /* Number of 512B blocks allocated */
+    pub attributes_mask: u64,  /* Mask to show what's supported in attributes */
+    pub atime: Timestamp,      /* Last access */
+    pub btime: Timestamp,      /* Creation */
+    pub ctime: Timestamp,      /* Last status change */
+    pub mtime: Timestamp,      /* Last modification */
+    pub rdev_major: u32,       /* Major ID, if this file represents a device */
+    pub rdev_minor: u32,       /* Minor ID, if this file represents a device */
+    pub dev_major: u32,        /* Major ID of the filesystem containing the file */
+    pub dev_minor: u32,        /* Minor ID of the filesystem containing the file */
+    pub mnt_id: u64,           /* Mount ID */
+    pub dio_mem_align: u32,    /* Memory buffer alignment for direct I/O */
+    pub dio_offset_align: u32, /* File offset alignment for direct I/O */
+    pub spare3: [u64; 12],
+}
+
+pub fn statx(
+    directory_file_descriptor: i32,
+    file_pathname: *const u8,
+    flags: usize,
+    mask: u32,
+    statx: &mut Statx,
+) -> crate::result::Result<isize> {
+    let syscall_result = Arch::syscall5(
+        NUMBER,
+        directory_file_descriptor as usize,
+        file_pathname as usize,
+        flags,
+        mask as usize,
+        statx as *mut Statx as usize,
+    );
+
+    handle_result(syscall_result)
+}
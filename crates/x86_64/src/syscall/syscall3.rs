@@ -1,11 +1,8 @@
 use crate::result::{Result, handle_result};
 
-use human::info;
-
 #[inline(always)]
 pub fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> Result<isize> {
     let ret: usize;
-    info!("crate: syscall3: \"");
     unsafe {
         core::arch::asm!(
             "syscall",
@@ -18,6 +15,5 @@ pub fn syscall3(n: usize, a1: usize, a2: usize, a3: usize) -> Result<isize> {
 
         );
     }
-    info!("\" .. done\n");
     handle_result(ret)
 }
@@ -0,0 +1,31 @@
+use human::warn;
+
+/// Report what the notes of a loaded object say about it, on standard error: the
+/// control-flow protections it was built for, and its build-id when `verbose`
+pub fn report(object: &elf::Elf, verbose: bool) {
+    if verbose {
+        match object.build_id() {
+            Ok(Some(build_id)) => {
+                warn!("Build ID: ");
+                for byte in build_id {
+                    warn!("{:02x}", byte);
+                }
+                warn!("\n");
+            }
+            Ok(None) => warn!("Build ID: none\n"),
+            Err(error) => warn!("Build ID: {:?}\n", error),
+        }
+    }
+
+    // xelf neither enables shadow stacks nor indirect branch tracking, so the marked
+    // program still runs, only without the protection it was built for
+    if let Ok(Some(features)) = object.x86_features()
+        && features.is_cet()
+    {
+        warn!("Warning: built for CET (");
+        for feature in features.iter() {
+            warn!(" {}", feature.as_str());
+        }
+        warn!(" ), which is not enforced\n");
+    }
+}
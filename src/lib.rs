@@ -1,6 +1,13 @@
 #![no_std]
+pub mod inspect;
+pub mod linker;
+pub mod loader;
 pub mod panic;
+pub mod relocate;
 pub mod result;
 
-pub use human::info;
+pub use human::{info, warn};
 pub use result::*;
+
+/// Environment variable that makes xelf explain what it does, on standard error
+pub const DEBUG: &str = "XELF_DEBUG";
@@ -0,0 +1,167 @@
+pub mod glibc;
+pub mod init;
+pub mod lazy;
+pub mod map;
+pub mod object;
+pub mod relocation;
+pub mod search;
+pub mod tls;
+
+use core::ffi::CStr;
+
+use arch::memory::Stack;
+use arch::memory::stack::Type;
+
+use crate::loader::{self, Placement};
+use crate::result::Result;
+use glibc::Glibc;
+use map::LinkMap;
+use object::Object;
+use search::PathBuffer;
+
+elf::define_error!(
+    Error,
+    TooManyObjects,
+    [
+        [TooManyObjects, -1, "Too many objects for the link map"],
+        [LibraryNotFound, -2, "Needed library not found"],
+        [UndefinedSymbol, -3, "Undefined symbol"],
+        [UnsupportedRelocation, -4, "Relocation type not supported"],
+        [
+            MissingProgram,
+            -5,
+            "Auxiliary vector does not describe the program"
+        ],
+        [
+            UnsupportedGlibc,
+            -6,
+            "glibc release whose private linker interface is not known"
+        ],
+        [
+            InvalidPltSlot,
+            -7,
+            "PLT slot bound lazily has no relocation"
+        ],
+        [InvalidObjectIndex, -8, "Object not in the link map"],
+        [
+            MissingTlsSegment,
+            -9,
+            "TLS symbol of an object without a PT_TLS segment"
+        ],
+    ]
+);
+
+/// Name under which glibc's libraries need its dynamic linker.
+///
+/// xelf loads it like any other library, for the variables and functions libc takes
+/// from it, and fills in the private state its own startup code would have (see
+/// `glibc::Glibc`).
+pub const GLIBC_LINKER_NAME: &str = "ld-linux-x86-64.so.2";
+
+/// Where glibc's dynamic linker is installed
+pub const GLIBC_LINKER: &str = "/lib64/ld-linux-x86-64.so.2";
+
+/// Whether the kernel started xelf as the `PT_INTERP` of another program, in which case
+/// `AT_BASE` is where xelf itself was loaded
+pub fn is_interpreter(stack: &Stack) -> bool {
+    let base = unsafe { stack.get_auxv_by_type(Type::Base.to()) };
+
+    base == Some(loader::own_span().0)
+}
+
+/// Append the libraries the objects of `map` need, breadth-first, skipping those
+/// already loaded
+fn load_needed(map: &mut LinkMap, context: &search::Context) -> Result<()> {
+    map.get(0).ok_or(Error::MissingProgram)?;
+    let mut index = 0;
+
+    while let Some(&object) = map.get(index) {
+        for needed in object.tables.dynamic.needed() {
+            let needed = needed?;
+            if map.find(needed).is_some() {
+                continue;
+            }
+
+            let path = search::find(needed, index, map, context).ok_or(Error::LibraryNotFound)?;
+            let image = loader::load_shared(path.as_str(), Placement::Anywhere)?;
+            let elf = unsafe { elf::Elf::from_image(image.start as *const u8)? };
+
+            map.push(Object::new(needed, path, elf, Some(image), Some(index))?)?;
+        }
+
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Link the program the kernel mapped before starting xelf as its interpreter: load
+/// the libraries it needs, relocate everything and run the initializers, then return
+/// the entry point to jump to with the stack left as the kernel built it
+pub fn run(stack: &mut Stack) -> Result<usize> {
+    let auxv = |atype: Type| unsafe { stack.get_auxv_by_type(atype.to()) };
+    let (Some(phdr), Some(phnum), Some(entry)) =
+        (auxv(Type::PHdr), auxv(Type::PHNum), auxv(Type::Entry))
+    else {
+        return Err(Error::MissingProgram.into());
+    };
+
+    // `run` is entered once, at startup, and leaves nothing borrowed from the map
+    let map = unsafe { map::startup() };
+    let elf = unsafe { elf::Elf::from_program_headers(phdr as *const u8, phnum)? };
+    let name = unsafe { stack.get_arg(0) }.unwrap_or("");
+    let path = unsafe { stack.get_auxv_by_type(Type::ExecFn.to()) }
+        .and_then(|pointer| unsafe { CStr::from_ptr(pointer as *const _) }.to_str().ok())
+        .unwrap_or(name);
+    let path = PathBuffer::from(path).unwrap_or_default();
+    map.push(Object::new(name, path, elf, None, None)?)?;
+
+    let context = search::Context::new(stack, &elf);
+    load_needed(map, &context)?;
+
+    let glibc = Glibc::find(map)?;
+    let layout = tls::layout(map)?;
+
+    // From here on the program's code may run and call back into `lazy::lazy_bind`
+    let map = map::freeze(map);
+
+    // glibc's thread descriptor sits at the thread pointer, which its libraries
+    // dereference even when nothing uses TLS
+    let descriptor = match glibc {
+        Some(glibc) => glibc.descriptor_size(map)?,
+        None => tls::HEADER_SIZE,
+    };
+    let pointer = match glibc.is_some() || !layout.is_empty() {
+        true => Some(tls::install(map, &layout, descriptor, stack)?),
+        false => None,
+    };
+
+    // Like glibc, any non-empty value asks for every slot to be bound now
+    let bind_now =
+        unsafe { stack.get_env_by_name(lazy::BIND_NOW) }.is_some_and(|value| !value.is_empty());
+    if !bind_now {
+        lazy::init();
+    }
+
+    // Dependencies first, so copy relocations and resolvers see relocated data
+    for index in (0..map.len()).rev() {
+        relocation::relocate(map, index, bind_now)?;
+    }
+
+    if let Some(pointer) = pointer {
+        tls::initialize(map, pointer);
+    }
+    if let (Some(glibc), Some(pointer)) = (glibc, pointer) {
+        glibc.setup(map, stack, pointer, &layout)?;
+    }
+    for object in map.iter() {
+        relocation::protect(object)?;
+    }
+    if let Some(glibc) = glibc {
+        glibc.early_init(map)?;
+    }
+
+    init::run(map, stack);
+
+    Ok(entry)
+}
@@ -0,0 +1,230 @@
+use core::ffi::CStr;
+
+use arch::memory::Stack;
+use arch::memory::stack::Type;
+use elf::dynamic::Tag;
+use syscall::mmap::{MapFlags, Prot, mmap};
+
+use super::map::LinkMap;
+use super::object::Object;
+use super::tls::Layout;
+use super::{Error, GLIBC_LINKER_NAME};
+use crate::result::Result;
+
+/// Soname of glibc's C library
+pub const LIBC_NAME: &str = "libc.so.6";
+
+/// Where glibc's private structures differ between releases: offsets in
+/// `_rtld_global_ro`, read from the code of the libc that uses them
+#[derive(Debug, Copy, Clone)]
+struct Release {
+    version: &'static str,
+    pagesize: usize,        /* `_dl_pagesize`, which `getpagesize` and malloc read */
+    tls_static_size: usize, /* `_dl_tls_static_size`, followed by `_dl_tls_static_align` */
+    hwcap: usize,           /* `_dl_hwcap`, which `getauxval` returns for `AT_HWCAP` */
+    auxv: usize,            /* `_dl_auxv`, which `getauxval` searches */
+    hwcap2: usize,          /* `_dl_hwcap2`, which `getauxval` returns for `AT_HWCAP2` */
+}
+
+/// Releases whose private structures xelf knows
+const RELEASES: [Release; 1] = [Release {
+    version: "2.36",
+    pagesize: 0x18,
+    tls_static_size: 0x2a0,
+    hwcap: 0x60,
+    auxv: 0x68,
+    hwcap2: 0x308,
+}];
+
+/// Offsets that do not change between releases: the first namespace of
+/// `_rtld_global`, and the public fields of `struct link_map` with `l_info` after them
+const NS_LOADED: usize = 0x00; /* `_dl_ns[0]._ns_loaded`, the program's link map */
+const NS_NLOADED: usize = 0x08; /* `_dl_ns[0]._ns_nloaded`, the objects it chains */
+const L_ADDR: usize = 0x00;
+const L_NAME: usize = 0x08;
+const L_LD: usize = 0x10;
+const L_REAL: usize = 0x28;
+const L_INFO: usize = 0x40;
+
+/// Tags below `DT_NUM` index `l_info` directly
+const DT_NUM: u64 = 35;
+
+/// Bytes mapped for the program's `struct link_map`, more than any release takes
+const LINK_MAP_SIZE: usize = 0x1000;
+
+/// What glibc's C library expects of its dynamic linker, which xelf loads as one more
+/// library without running any of its code but the `IRELATIVE` resolver that detects the
+/// CPU's features.
+///
+/// xelf fills in what the start of a program reads: the variables the linker exports,
+/// the static TLS sizes, the program's `struct link_map`, whose initializers
+/// `__libc_start_main` runs, and the thread descriptor's id and list. Threads and
+/// `dlopen`, which need the linker's own bookkeeping, are left out.
+#[derive(Debug, Copy, Clone)]
+pub struct Glibc {
+    libc: usize,      /* Position of the C library in the link map */
+    linker: usize,    /* Position of the dynamic linker */
+    release: Release, /* Layout of their private structures */
+}
+
+/// Run-time address of the symbol `name` of `object`
+fn symbol(object: &Object, name: &str) -> Result<usize> {
+    match object.elf.lookup(name)? {
+        Some((_, symbol)) => Ok(object.value(&symbol)),
+        None => Err(Error::UnsupportedGlibc.into()),
+    }
+}
+
+/// A `uint32_t` descriptor libc exports for `libthread_db`: `_thread_db_sizeof_*` hold
+/// one, `_thread_db_<struct>_<field>` the field's size in bits, count and offset
+fn thread_db(libc: &Object, name: &str, index: usize) -> Result<usize> {
+    let descriptor = symbol(libc, name)? as *const u32;
+    Ok(unsafe { descriptor.add(index).read() } as usize)
+}
+
+fn write<T>(address: usize, value: T) {
+    unsafe { (address as *mut T).write_unaligned(value) };
+}
+
+impl Glibc {
+    /// glibc's C library and dynamic linker in `map`, none for programs without them
+    pub fn find(map: &LinkMap) -> Result<Option<Self>> {
+        let (Some(libc), Some(linker)) = (map.find(LIBC_NAME), map.find(GLIBC_LINKER_NAME)) else {
+            return Ok(None);
+        };
+
+        // It only returns the address of a constant string, so it can run unrelocated
+        let object = map.get(libc).ok_or(Error::InvalidObjectIndex)?;
+        let version: extern "C" fn() -> *const core::ffi::c_char =
+            unsafe { core::mem::transmute(symbol(object, "gnu_get_libc_version")?) };
+        let version = unsafe { CStr::from_ptr(version()) }.to_bytes();
+
+        let release = RELEASES
+            .into_iter()
+            .find(|release| release.version.as_bytes() == version)
+            .ok_or(Error::UnsupportedGlibc)?;
+
+        Ok(Some(Self {
+            libc,
+            linker,
+            release,
+        }))
+    }
+
+    fn libc<'a>(&self, map: &'a LinkMap) -> Result<&'a Object> {
+        Ok(map.get(self.libc).ok_or(Error::InvalidObjectIndex)?)
+    }
+
+    fn linker<'a>(&self, map: &'a LinkMap) -> Result<&'a Object> {
+        Ok(map.get(self.linker).ok_or(Error::InvalidObjectIndex)?)
+    }
+
+    /// Bytes of glibc's thread descriptor, `struct pthread`, which sits at the thread
+    /// pointer
+    pub fn descriptor_size(&self, map: &LinkMap) -> Result<usize> {
+        thread_db(self.libc(map)?, "_thread_db_sizeof_pthread", 0)
+    }
+
+    /// Fill in what the linker would have before running any code of the program: to
+    /// be called once everything is relocated, before `PT_GNU_RELRO` is made read-only,
+    /// with the thread pointer `tls::install` returned for the static TLS `layout`
+    pub fn setup(
+        &self,
+        map: &LinkMap,
+        stack: &Stack,
+        pointer: usize,
+        layout: &Layout,
+    ) -> Result<()> {
+        let (libc, linker) = (self.libc(map)?, self.linker(map)?);
+        let auxv = |atype: Type| unsafe { stack.get_auxv_by_type(atype.to()) }.unwrap_or(0);
+
+        write(symbol(linker, "__libc_stack_end")?, stack.pointer as usize);
+        write(symbol(linker, "_dl_argv")?, stack.argv as usize);
+        write(
+            symbol(linker, "__libc_enable_secure")?,
+            (auxv(Type::Secure) != 0) as i32,
+        );
+
+        let ro = symbol(linker, "_rtld_global_ro")?;
+        let descriptor = self.descriptor_size(map)?;
+        write(ro + self.release.pagesize, auxv(Type::PageSz));
+        write(ro + self.release.tls_static_size, layout.size + descriptor);
+        write(ro + self.release.tls_static_size + 8, layout.align);
+        write(ro + self.release.hwcap, auxv(Type::HwCap));
+        write(ro + self.release.auxv, stack.auxv as usize);
+        write(ro + self.release.hwcap2, auxv(Type::HwCap2));
+
+        let global = symbol(linker, "_rtld_global")?;
+        write(global + NS_LOADED, self.link_map(map)?);
+        write(global + NS_NLOADED, 1u32);
+
+        // The initial thread is the one thread of `_dl_stack_user`, and none has a
+        // stack of glibc's making in `_dl_stack_used`
+        let user = global + thread_db(libc, "_thread_db_rtld_global__dl_stack_user", 2)?;
+        let used = global + thread_db(libc, "_thread_db_rtld_global__dl_stack_used", 2)?;
+        let list = pointer + thread_db(libc, "_thread_db_pthread_list", 2)?;
+        write(used, [used, used]);
+        write(user, [list, list]);
+        write(list, [user, user]);
+
+        let tid = pointer + thread_db(libc, "_thread_db_pthread_tid", 2)?;
+        let id = syscall::set_tid_address(tid as *mut i32)?;
+        write(tid, id as i32);
+
+        Ok(())
+    }
+
+    /// Map the program's `struct link_map`, with the `l_info` entries `__libc_start_main`
+    /// reads to run its initializers
+    fn link_map(&self, map: &LinkMap) -> Result<usize> {
+        let program = map.get(0).ok_or(Error::MissingProgram)?;
+        let dynamic = program
+            .elf
+            .program_headers()?
+            .find(elf::program::Type::Dynamic)
+            .ok_or(Error::MissingProgram)?;
+        let dynamic = program.address(dynamic.vaddr);
+
+        let link_map = mmap(
+            0,
+            LINK_MAP_SIZE,
+            Prot::Read | Prot::Write,
+            MapFlags::Private | MapFlags::Anonymous,
+            -1,
+            0,
+        )?
+        .to();
+
+        write(link_map + L_ADDR, program.bias);
+        write(link_map + L_NAME, c"".as_ptr() as usize);
+        write(link_map + L_LD, dynamic);
+        write(link_map + L_REAL, link_map);
+
+        // Each entry points at the program's `Elf64_Dyn` of that tag
+        let entries = dynamic as *const [u64; 2];
+        for index in 0.. {
+            let [tag, _] = unsafe { entries.add(index).read() };
+            match tag {
+                tag if tag == Tag::Null.to() as u64 => break,
+                tag if tag < DT_NUM => {
+                    write(
+                        link_map + L_INFO + tag as usize * 8,
+                        unsafe { entries.add(index) } as usize,
+                    )
+                }
+                _ => {}
+            }
+        }
+
+        Ok(link_map)
+    }
+
+    /// Let libc initialize itself, as the linker does before running any initializer
+    pub fn early_init(&self, map: &LinkMap) -> Result<()> {
+        let early_init: extern "C" fn(initial: bool) =
+            unsafe { core::mem::transmute(symbol(self.libc(map)?, "__libc_early_init")?) };
+        early_init(true);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use arch::memory::Stack;
+use elf::dynamic::Tag;
+
+use super::map::LinkMap;
+use super::object::Object;
+
+/// Start routine of the C library, which runs the program's initializers itself
+pub const START_MAIN: &str = "__libc_start_main";
+
+/// A `DT_INIT` function or `DT_INIT_ARRAY` entry, called as glibc calls them
+type Initializer = extern "C" fn(argc: i32, argv: *mut *mut u8, envp: *mut *mut u8);
+
+fn call(address: usize, stack: &Stack) {
+    let initializer: Initializer = unsafe { core::mem::transmute(address) };
+    initializer(stack.argc as i32, stack.argv, stack.envp);
+}
+
+/// Call the functions of an array given by its address and size tags
+fn array(object: &Object, address: Tag, size: Tag, stack: &Stack) {
+    let (Some(address), Some(size)) = (
+        object.tables.dynamic.get(address),
+        object.tables.dynamic.get(size),
+    ) else {
+        return;
+    };
+
+    let functions = object.address(address) as *const usize;
+    for index in 0..size as usize / size_of::<usize>() {
+        // Entries of 0 and -1 are placeholders some linkers leave
+        match unsafe { functions.add(index).read() } {
+            0 | usize::MAX => {}
+            function => call(function, stack),
+        }
+    }
+}
+
+/// Whether the start code of `program` runs its own `DT_INIT` and `DT_INIT_ARRAY`
+/// before `main`, as C libraries' `__libc_start_main` does
+fn runs_own_initializers(program: &Object) -> bool {
+    program
+        .elf
+        .dynamic_symbols()
+        .ok()
+        .and_then(|symbols| symbols.find(START_MAIN))
+        .is_some_and(|(_, symbol)| symbol.is_undefined())
+}
+
+/// Run the initializers of the link map: the program's `DT_PREINIT_ARRAY`, then each
+/// object's `DT_INIT` and `DT_INIT_ARRAY`, dependencies first and the program last.
+///
+/// The program's own `DT_INIT` and `DT_INIT_ARRAY` are skipped when it imports
+/// `__libc_start_main`, which runs them itself; other programs, such as those built
+/// with `-nostdlib`, rely on xelf for them.
+pub fn run(map: &LinkMap, stack: &Stack) {
+    if let Some(program) = map.get(0) {
+        array(program, Tag::PreInitArray, Tag::PreInitArraySz, stack);
+    }
+
+    for object in map.iter().rev() {
+        if object.image.is_none() && runs_own_initializers(object) {
+            continue;
+        }
+
+        if let Some(init) = object.tables.dynamic.get(Tag::Init) {
+            call(object.address(init), stack);
+        }
+        array(object, Tag::InitArray, Tag::InitArraySz, stack);
+    }
+}
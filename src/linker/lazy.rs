@@ -0,0 +1,61 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use elf::dynamic::Tag;
+use human::warn;
+use result::ErrorTrait;
+
+use super::Error;
+use super::map;
+use super::object::Object;
+use super::relocation;
+
+/// Environment variable that makes every object bind its PLT slots at load time
+pub const BIND_NOW: &str = "LD_BIND_NOW";
+
+unsafe extern "C" {
+    /// Where PLT0 jumps for slots not bound yet, in `src/trampoline.s`
+    fn runtime_resolve();
+}
+
+/// Bytes the trampoline saves the vector registers in with `XSAVE`, zero to use `FXSAVE`
+#[unsafe(no_mangle)]
+static XELF_STATE_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+/// Size the trampoline's register save area for this machine
+pub fn init() {
+    XELF_STATE_SIZE.store(arch::xsave_size().unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Whether the PLT slots of `object` may wait for their first call: it must have a
+/// `DT_PLTGOT` to reach the trampoline through, and not ask to be bound at load time
+pub fn is_lazy(object: &Object) -> bool {
+    object.tables.dynamic.contains(Tag::PltGot) && !object.tables.dynamic.bind_now()
+}
+
+/// Fill the words of the GOT that PLT0 uses: GOT[1], which it pushes, with the place
+/// of `object` in the link map, and GOT[2], which it jumps to, with the trampoline
+pub fn install(object: &Object, index: usize) {
+    let Some(got) = object.tables.dynamic.get(Tag::PltGot) else {
+        return;
+    };
+
+    let got = object.address(got) as *mut usize;
+    unsafe {
+        got.add(1).write(index);
+        got.add(2).write(runtime_resolve as *const () as usize);
+    }
+}
+
+/// Called by the trampoline with the words PLT0 and the slot pushed: bind the slot
+/// and return the function to continue into
+#[unsafe(no_mangle)]
+pub extern "C" fn lazy_bind(index: usize, slot: usize) -> usize {
+    let map = map::frozen().ok_or(Error::InvalidObjectIndex.into());
+    match map.and_then(|map| relocation::bind(map, index, slot)) {
+        Ok(function) => function,
+        Err(error) => {
+            warn!("xelf2: {}\n", error.describe());
+            syscall::exit(127);
+        }
+    }
+}
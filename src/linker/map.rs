@@ -0,0 +1,122 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use super::Error;
+use super::object::Object;
+use crate::result::Result;
+
+/// Most objects a program may bring in, itself included
+pub const CAPACITY: usize = 64;
+
+/// The objects of a program in load order: the program first, then its libraries
+/// breadth-first as they are needed
+#[derive(Debug)]
+pub struct LinkMap {
+    objects: [Option<Object>; CAPACITY],
+    count: usize,
+}
+
+impl LinkMap {
+    pub const fn new() -> Self {
+        Self {
+            objects: [const { None }; CAPACITY],
+            count: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Object> {
+        self.objects.get(index)?.as_ref()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Object> {
+        self.objects[..self.count].iter().flatten()
+    }
+
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut Object> {
+        self.objects[..self.count].iter_mut().flatten()
+    }
+
+    /// Append an object and return its index
+    pub fn push(&mut self, object: Object) -> Result<usize> {
+        let slot = self
+            .objects
+            .get_mut(self.count)
+            .ok_or(Error::TooManyObjects)?;
+        *slot = Some(object);
+        self.count += 1;
+
+        Ok(self.count - 1)
+    }
+
+    /// Index of the object answering to `name`
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.iter().position(|object| object.is(name))
+    }
+}
+
+impl Default for LinkMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The link map of the running program.
+///
+/// The program jumps back into the linker to bind symbols, long after `entry` left, so
+/// the map lives in a static. It is written only while the libraries are loaded, before
+/// any code of theirs runs; once frozen it is shared with whichever thread binds a slot,
+/// and the GOT slots, reached through raw pointers, are all that still changes.
+static mut LINK_MAP: LinkMap = LinkMap::new();
+
+/// Set when loading is over and the map may only be read
+static FROZEN: AtomicBool = AtomicBool::new(false);
+
+/// The link map to fill while loading the program's libraries.
+///
+/// # Safety
+///
+/// Called once, before `freeze`: the reference returned must be the only one to the map.
+pub unsafe fn startup() -> &'static mut LinkMap {
+    unsafe { &mut *core::ptr::addr_of_mut!(LINK_MAP) }
+}
+
+/// End loading, giving up the only mutable reference to the map for a shared one
+pub fn freeze(map: &'static mut LinkMap) -> &'static LinkMap {
+    FROZEN.store(true, Ordering::Release);
+    map
+}
+
+/// The link map lazy binding resolves in, once loading froze it
+pub fn frozen() -> Option<&'static LinkMap> {
+    FROZEN
+        .load(Ordering::Acquire)
+        .then(|| unsafe { &*core::ptr::addr_of!(LINK_MAP) })
+}
+
+/// The link map is the global scope symbols resolve in
+impl elf::scope::Scope<'static> for LinkMap {
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn get(&self, position: usize) -> Option<&elf::Elf<'static>> {
+        LinkMap::get(self, position).map(|object| &object.elf)
+    }
+
+    fn tables(&self, position: usize) -> elf::Result<elf::scope::Tables<'static>> {
+        LinkMap::get(self, position)
+            .map(|object| object.tables)
+            .ok_or(elf::Error::InvalidScopeIndex)
+    }
+
+    fn exports_plt(&self, position: usize) -> bool {
+        LinkMap::get(self, position).is_some_and(|object| object.exports_plt)
+    }
+}
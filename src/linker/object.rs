@@ -0,0 +1,68 @@
+use elf::relocation::Relocations;
+use elf::scope::Tables;
+use elf::symbol::{Index, Symbol};
+
+use super::search::PathBuffer;
+use super::tls::Block;
+use crate::loader::Image;
+use crate::result::Result;
+
+/// An object of the link map: the program or one of the libraries it needs
+#[derive(Debug, Copy, Clone)]
+pub struct Object {
+    pub name: &'static str,        /* Name it was needed as, or the program path */
+    pub path: PathBuffer,          /* Where it was found, for `$ORIGIN` */
+    pub elf: elf::Elf<'static>,    /* Its mapped image */
+    pub tables: Tables<'static>,   /* Its dynamic array and symbol tables, parsed once */
+    pub plt: Relocations<'static>, /* Relocations of its PLT slots */
+    pub bias: usize,               /* Mapped minus linked addresses */
+    pub image: Option<Image>,      /* Mapping made by xelf, none for the program */
+    pub loader: Option<usize>,     /* Position of the object that needed it */
+    pub exports_plt: bool,         /* Whether it has canonical PLT entries */
+    pub tls: Option<Block>,        /* Its static TLS block, once laid out */
+}
+
+impl Object {
+    pub fn new(
+        name: &'static str,
+        path: PathBuffer,
+        elf: elf::Elf<'static>,
+        image: Option<Image>,
+        loader: Option<usize>,
+    ) -> Result<Self> {
+        Ok(Self {
+            name,
+            path,
+            elf,
+            tables: Tables::parse(&elf)?,
+            plt: elf.plt_relocations()?,
+            bias: elf.bias() as usize,
+            image,
+            loader,
+            exports_plt: elf::scope::exports_plt(&elf),
+            tls: None,
+        })
+    }
+
+    /// Run-time address of a linked address of the object
+    pub fn address(&self, value: u64) -> usize {
+        self.bias.wrapping_add(value as usize)
+    }
+
+    /// Run-time value of one of its symbols, which absolute symbols keep as linked
+    pub fn value(&self, symbol: &Symbol) -> usize {
+        match symbol.shndx {
+            Index::Absolute => symbol.value as usize,
+            _ => self.address(symbol.value),
+        }
+    }
+
+    pub fn soname(&self) -> Option<&'static str> {
+        self.tables.dynamic.soname().ok().flatten()
+    }
+
+    /// Whether the object answers to `name` in another object's `DT_NEEDED`
+    pub fn is(&self, name: &str) -> bool {
+        self.name == name || self.soname() == Some(name)
+    }
+}
@@ -0,0 +1,213 @@
+use arch::memory::page;
+use elf::program;
+use elf::relocation::Relocation;
+use elf::relocation::kind::Kind;
+use elf::relocation::x86_64::Type;
+use elf::scope::{self, Class};
+use elf::symbol;
+use human::warn;
+use syscall::mmap::{Address, Prot};
+use syscall::mprotect;
+
+use super::Error;
+use super::lazy;
+use super::map::LinkMap;
+use super::object::Object;
+use super::tls::Block;
+use crate::result::Result;
+
+/// An `STT_GNU_IFUNC` resolver, returning the implementation to use
+type Resolver = extern "C" fn() -> usize;
+
+/// Address a definition stands for, running its resolver when it is an indirect function
+fn value(object: &Object, symbol: &symbol::Symbol) -> usize {
+    let address = object.value(symbol);
+
+    match symbol.stype {
+        symbol::Type::GnuIFunc => {
+            let resolver: Resolver = unsafe { core::mem::transmute(address) };
+            resolver()
+        }
+        _ => address,
+    }
+}
+
+/// Resolve the symbol a relocation of the object at `index` refers to in the link map,
+/// and return the object defining it with its definition, none for a weak reference
+/// nothing defines
+fn resolve(
+    map: &LinkMap,
+    index: usize,
+    symbol: u32,
+    class: Class,
+) -> Result<Option<(&Object, symbol::Symbol)>> {
+    match scope::resolve(map, index, symbol as usize, class) {
+        Ok(Some(definition)) => {
+            let object = map
+                .get(definition.object)
+                .ok_or(Error::InvalidObjectIndex)?;
+            Ok(Some((object, definition.symbol)))
+        }
+        Ok(None) => Ok(None),
+        Err(elf::Error::UndefinedSymbol) => {
+            let object = map.get(index).ok_or(Error::InvalidObjectIndex)?;
+            let reference = scope::Reference::of(&object.tables, symbol as usize)?;
+            match reference.version {
+                Some(version) => warn!(
+                    "xelf: {}: undefined symbol {}@{}\n",
+                    object.name, reference.name, version
+                ),
+                None => warn!(
+                    "xelf: {}: undefined symbol {}\n",
+                    object.name, reference.name
+                ),
+            }
+            Err(Error::UndefinedSymbol.into())
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Value of a TLS relocation of the object at `index` against `symbol`, computed from
+/// the block of the object defining it and the offset of the definition in that block:
+/// symbol 0 stands for offset 0 in the object's own block
+fn tls(
+    map: &LinkMap,
+    index: usize,
+    object: &Object,
+    symbol: u32,
+    value: impl Fn(&Block, usize) -> usize,
+) -> Result<usize> {
+    let definition = match symbol {
+        0 => Some((object, 0)),
+        symbol => resolve(map, index, symbol, Class::Data)?
+            .map(|(definition, symbol)| (definition, symbol.value as usize)),
+    };
+
+    match definition {
+        Some((definition, offset)) => {
+            let block = definition.tls.ok_or(Error::MissingTlsSegment)?;
+            Ok(value(&block, offset))
+        }
+        None => Ok(0),
+    }
+}
+
+/// Apply one relocation of the object at `index`
+fn apply(map: &LinkMap, index: usize, object: &Object, relocation: &Relocation) -> Result<()> {
+    let place = object.address(relocation.offset) as *mut usize;
+    let addend = match relocation.addend {
+        Some(addend) => addend as usize,
+        None => unsafe { place.read_unaligned() },
+    };
+    let symbol = |class| -> Result<usize> {
+        Ok(resolve(map, index, relocation.symbol, class)?
+            .map_or(0, |(definition, symbol)| value(definition, &symbol)))
+    };
+
+    let Kind::X86_64(rtype) = relocation.kind else {
+        return Err(Error::UnsupportedRelocation.into());
+    };
+
+    let word = match rtype {
+        Type::None => return Ok(()),
+        Type::Relative => object.bias.wrapping_add(addend),
+        Type::Abs64 | Type::GlobDat => symbol(Class::Data)?.wrapping_add(addend),
+        Type::JumpSlot => symbol(Class::Plt)?.wrapping_add(addend),
+        Type::IRelative => {
+            let resolver: Resolver =
+                unsafe { core::mem::transmute(object.bias.wrapping_add(addend)) };
+            resolver()
+        }
+        Type::DtpMod64 => tls(map, index, object, relocation.symbol, |block, _| {
+            block.module
+        })?,
+        Type::DtpOff64 => tls(map, index, object, relocation.symbol, |_, offset| {
+            offset.wrapping_add(addend)
+        })?,
+        Type::TpOff64 => tls(map, index, object, relocation.symbol, |block, offset| {
+            offset.wrapping_add(addend).wrapping_sub(block.offset)
+        })?,
+        Type::Copy => {
+            // The program holds the copy, so the definition is looked up past it
+            if let Some((definition, symbol)) = resolve(map, index, relocation.symbol, Class::Copy)?
+            {
+                let source = definition.address(symbol.value) as *const u8;
+                unsafe {
+                    core::ptr::copy_nonoverlapping(source, place as *mut u8, symbol.size as usize)
+                };
+            }
+            return Ok(());
+        }
+        rtype => {
+            warn!(
+                "xelf: {}: unsupported relocation {}\n",
+                object.name,
+                rtype.name()
+            );
+            return Err(Error::UnsupportedRelocation.into());
+        }
+    };
+
+    unsafe { place.write_unaligned(word) };
+    Ok(())
+}
+
+/// Apply every relocation of the object at `index` in `map`.
+///
+/// Unless `bind_now` or the object itself asks otherwise, PLT slots are left pointing
+/// back into the PLT and bound through the trampoline on their first call.
+pub fn relocate(map: &LinkMap, index: usize, bind_now: bool) -> Result<()> {
+    let object = map.get(index).ok_or(Error::InvalidObjectIndex)?;
+    let lazy = !bind_now && lazy::is_lazy(object);
+
+    for relocations in [
+        object.elf.relative_relocations()?,
+        object.elf.dynamic_relocations()?,
+    ] {
+        for relocation in relocations {
+            apply(map, index, object, &relocation)?;
+        }
+    }
+
+    for relocation in object.plt {
+        match relocation.kind {
+            // The slot holds the linked address of its PLT entry's push, which leads to PLT0
+            Kind::X86_64(Type::JumpSlot) if lazy => {
+                let place = object.address(relocation.offset) as *mut usize;
+                unsafe { place.write_unaligned(object.address(place.read_unaligned() as u64)) };
+            }
+            _ => apply(map, index, object, &relocation)?,
+        }
+    }
+
+    if lazy {
+        lazy::install(object, index);
+    }
+
+    Ok(())
+}
+
+/// Make the `PT_GNU_RELRO` region of a relocated object read-only
+pub fn protect(object: &Object) -> Result<()> {
+    if let Some(relro) = object.elf.program_headers()?.find(program::Type::GnuRelro) {
+        let start = page::truncate_to_page(object.address(relro.vaddr) as u64) as usize;
+        let end = page::truncate_to_page(object.address(relro.vaddr + relro.memsz) as u64) as usize;
+        if end > start {
+            mprotect(Address(start), end - start, Prot::Read.into())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Bind the PLT slot that relocation `slot` of the object at `index` fills, and return
+/// the function it now holds
+pub fn bind(map: &LinkMap, index: usize, slot: usize) -> Result<usize> {
+    let object = map.get(index).ok_or(Error::InvalidObjectIndex)?;
+    let relocation = object.plt.get(slot).ok_or(Error::InvalidPltSlot)?;
+
+    apply(map, index, object, &relocation)?;
+
+    Ok(unsafe { (object.address(relocation.offset) as *const usize).read_unaligned() })
+}
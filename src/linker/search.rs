@@ -0,0 +1,467 @@
+use arch::memory::Stack;
+use arch::memory::stack::Type;
+use elf::cache::{Abi, Cache};
+use elf::dynamic::Flag1;
+use elf::header::{Class, Machine};
+use human::{info, warn};
+use syscall::File;
+use syscall::file::path::PATH_MAX;
+use syscall::mmap::Prot;
+use syscall::open::flags::Flag;
+
+use super::GLIBC_LINKER;
+use super::map::LinkMap;
+
+/// Directories searched when nothing else names the library
+pub const DEFAULT: [&str; 6] = [
+    "/lib/x86_64-linux-gnu",
+    "/usr/lib/x86_64-linux-gnu",
+    "/lib64",
+    "/usr/lib64",
+    "/lib",
+    "/usr/lib",
+];
+
+/// Cache `ldconfig` builds of the libraries in the system directories
+pub const CACHE: &str = "/etc/ld.so.cache";
+
+/// Environment variable listing directories searched before `DT_RUNPATH`
+pub const LIBRARY_PATH: &str = "LD_LIBRARY_PATH";
+
+/// What `$LIB` expands to when the location of glibc's linker does not tell
+pub const LIB: &str = "lib64";
+
+/// Log a search decision when `XELF_DEBUG` asks for it
+macro_rules! trace {
+    ($context:expr, $($arg:tt)*) => {
+        if $context.verbose {
+            info!($($arg)*);
+        }
+    };
+}
+
+/// A path built in place, long enough for anything the kernel accepts
+#[derive(Copy, Clone)]
+pub struct PathBuffer {
+    bytes: [u8; PATH_MAX], /* Path, not terminated */
+    length: usize,         /* Bytes in use */
+}
+
+impl core::fmt::Debug for PathBuffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Default for PathBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PathBuffer {
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; PATH_MAX],
+            length: 0,
+        }
+    }
+
+    pub fn from(path: &str) -> Option<Self> {
+        let mut buffer = Self::new();
+        buffer.push(path)?;
+        Some(buffer)
+    }
+
+    /// Append `text`, leaving room for the terminating NUL the kernel needs
+    pub fn push(&mut self, text: &str) -> Option<()> {
+        let length = self.length + text.len();
+        if length >= PATH_MAX {
+            return None;
+        }
+
+        self.bytes[self.length..length].copy_from_slice(text.as_bytes());
+        self.length = length;
+        Some(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only whole strings are ever pushed
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or("")
+    }
+
+    /// Directory holding the file, as `$ORIGIN` expands to
+    pub fn directory(&self) -> &str {
+        match self.as_str().rsplit_once('/') {
+            Some(("", _)) => "/",
+            Some((directory, _)) => directory,
+            None => ".",
+        }
+    }
+}
+
+/// Where a library was looked for, to explain the search in the logs
+#[derive(Debug, Copy, Clone)]
+pub enum Source {
+    RPath,
+    LibraryPath,
+    RunPath,
+    Cache,
+    Default,
+}
+
+impl Source {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RPath => "DT_RPATH",
+            Self::LibraryPath => LIBRARY_PATH,
+            Self::RunPath => "DT_RUNPATH",
+            Self::Cache => CACHE,
+            Self::Default => "default directories",
+        }
+    }
+}
+
+/// What the search takes from the process: the environment, whether it runs with
+/// elevated privileges and the kind of objects the program can be linked with
+#[derive(Debug, Copy, Clone)]
+pub struct Context {
+    pub library_path: Option<&'static str>, /* `LD_LIBRARY_PATH`, unless secure */
+    pub platform: &'static str,             /* What `$PLATFORM` expands to */
+    pub secure: bool,                       /* `AT_SECURE`: setuid or similar */
+    pub verbose: bool,                      /* `XELF_DEBUG` is set */
+    pub cache: Option<Cache<'static>>,      /* `/etc/ld.so.cache`, when readable */
+    pub hwcaps: &'static [&'static str],    /* `glibc-hwcaps` usable here, best first */
+    pub lib: PathBuffer,                    /* What `$LIB` expands to */
+    pub class: Class,                       /* Class of the program */
+    pub machine: Machine,                   /* Machine of the program */
+}
+
+impl Context {
+    pub fn new(stack: &Stack, program: &elf::Elf) -> Self {
+        let secure =
+            unsafe { stack.get_auxv_by_type(Type::Secure.to()) }.is_some_and(|value| value != 0);
+
+        let verbose = unsafe { stack.get_env_by_name(crate::DEBUG) }.is_some();
+
+        let library_path = unsafe { stack.get_env_by_name(LIBRARY_PATH) };
+        if secure && library_path.is_some() && verbose {
+            info!("xelf: ignoring {} in secure mode\n", LIBRARY_PATH);
+        }
+
+        let platform = unsafe { stack.get_auxv_by_type(Type::Platform.to()) }
+            .and_then(|pointer| {
+                unsafe { core::ffi::CStr::from_ptr(pointer as *const _) }
+                    .to_str()
+                    .ok()
+            })
+            .unwrap_or(arch::PLATFORM.to_str().unwrap_or(""));
+
+        Self {
+            library_path: library_path.filter(|_| !secure),
+            platform,
+            secure,
+            verbose,
+            cache: cache(verbose),
+            hwcaps: arch::hwcaps(),
+            lib: lib(),
+            class: program.header.ident.class,
+            machine: program.header.machine,
+        }
+    }
+}
+
+/// What `$LIB` expands to: the directory glibc's linker lives in, as glibc's build
+/// configuration sets both, such as `lib/x86_64-linux-gnu` where `/lib64` only holds a
+/// link to it and `lib64` where the linker is there itself
+fn lib() -> PathBuffer {
+    let mut target = [0; PATH_MAX];
+    let linker = match syscall::readlink(GLIBC_LINKER, &mut target) {
+        Ok(target) => core::str::from_utf8(target).unwrap_or(GLIBC_LINKER),
+        Err(_) => GLIBC_LINKER,
+    };
+
+    // A relative link stays within the directory it sits in
+    let linker = if linker.starts_with('/') {
+        linker
+    } else {
+        GLIBC_LINKER
+    };
+
+    PathBuffer::from(linker)
+        .and_then(|path| PathBuffer::from(path.directory().trim_start_matches('/')))
+        .unwrap_or_else(|| PathBuffer::from(LIB).unwrap_or_default())
+}
+
+/// Map and parse the cache once, for the lifetime of the process
+fn cache(verbose: bool) -> Option<Cache<'static>> {
+    let file = File::open(CACHE, Flag::RDONLY as usize | Flag::CLOEXEC as usize).ok()?;
+    let mapping = file.map(Prot::Read.into()).ok()?;
+    let bytes = unsafe { core::slice::from_raw_parts(mapping.as_ptr(), mapping.length) };
+    core::mem::forget(mapping);
+
+    match Cache::parse(bytes) {
+        Ok(cache) => Some(cache),
+        Err(error) => {
+            if verbose {
+                info!("xelf: ignoring {}: {:?}\n", CACHE, error);
+            }
+            None
+        }
+    }
+}
+
+/// Write `directory` to `buffer`, expanding `$ORIGIN`, `$LIB` and `$PLATFORM` in both
+/// plain and braced form. Secure processes may not use `$ORIGIN`, as the object could
+/// sit anywhere an attacker chose.
+pub fn expand(
+    directory: &str,
+    origin: &str,
+    context: &Context,
+    buffer: &mut PathBuffer,
+) -> Option<()> {
+    const TOKENS: [&str; 3] = ["ORIGIN", "LIB", "PLATFORM"];
+
+    let mut rest = directory;
+    while let Some(dollar) = rest.find('$') {
+        buffer.push(&rest[..dollar])?;
+        rest = &rest[dollar + 1..];
+
+        let (token, length) = TOKENS
+            .iter()
+            .find_map(|token| {
+                if rest
+                    .strip_prefix('{')?
+                    .strip_prefix(token)?
+                    .starts_with('}')
+                {
+                    Some((*token, token.len() + 2))
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                // Unbraced tokens end where the name does
+                TOKENS.iter().find_map(|token| {
+                    let after = rest.strip_prefix(token)?;
+                    let ends = !after.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+                    ends.then_some((*token, token.len()))
+                })
+            })?;
+
+        let value = match token {
+            "ORIGIN" if context.secure => return None,
+            "ORIGIN" => origin,
+            "LIB" => context.lib.as_str(),
+            _ => context.platform,
+        };
+        buffer.push(value)?;
+        rest = &rest[length..];
+    }
+
+    buffer.push(rest)
+}
+
+/// What a path the search comes across holds
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Candidate {
+    Missing, /* Nothing xelf can open */
+    Foreign, /* Not an object of the program's class and machine */
+    Usable,
+}
+
+/// Whether `path` holds an object the program can be linked with. Others, such as the
+/// 32-bit libraries of a multilib system, are passed over as glibc does.
+pub fn candidate(path: &str, context: &Context) -> Candidate {
+    let Ok(file) = File::open(path, Flag::RDONLY as usize | Flag::CLOEXEC as usize) else {
+        return Candidate::Missing;
+    };
+
+    let mut bytes = [0; 64];
+    let header = match file.read_exact_at(&mut bytes, 0) {
+        Ok(()) => elf::Header::parse(&bytes),
+        Err(_) => return Candidate::Foreign,
+    };
+
+    match header {
+        Ok(header) if header.ident.class == context.class && header.machine == context.machine => {
+            Candidate::Usable
+        }
+        _ => Candidate::Foreign,
+    }
+}
+
+/// Look for `name` in each directory of a colon-separated list, an empty entry
+/// standing for the current directory
+fn search(
+    name: &str,
+    list: &str,
+    origin: &str,
+    source: Source,
+    context: &Context,
+) -> Option<PathBuffer> {
+    for directory in list.split([':', ';']) {
+        let mut path = PathBuffer::new();
+        let joined = expand(
+            if directory.is_empty() { "." } else { directory },
+            origin,
+            context,
+            &mut path,
+        )
+        .and_then(|_| path.push("/"))
+        .and_then(|_| path.push(name));
+
+        if joined.is_none() {
+            trace!(
+                context,
+                "xelf: {}: skipping {} entry {}\n",
+                name,
+                source.as_str(),
+                directory
+            );
+            continue;
+        }
+
+        match candidate(path.as_str(), context) {
+            Candidate::Usable => {
+                trace!(
+                    context,
+                    "xelf: {}: found at {} through {}\n",
+                    name,
+                    path.as_str(),
+                    source.as_str()
+                );
+                return Some(path);
+            }
+            Candidate::Foreign => {
+                trace!(
+                    context,
+                    "xelf: {}: skipping {}, built for another machine\n",
+                    name,
+                    path.as_str()
+                );
+            }
+            Candidate::Missing => trace!(context, "xelf: {}: not at {}\n", name, path.as_str()),
+        }
+    }
+
+    None
+}
+
+/// Look for `name` in the cache, in the best `glibc-hwcaps` subdirectory usable here
+fn lookup(name: &str, cache: &Cache, context: &Context) -> Option<PathBuffer> {
+    let entry = match cache.lookup(name, Abi::X8664Lib64, context.hwcaps) {
+        Ok(entry) => entry?,
+        Err(error) => {
+            trace!(
+                context,
+                "xelf: {}: unreadable {}: {:?}\n", name, CACHE, error
+            );
+            return None;
+        }
+    };
+
+    // The cache may be older than the files it lists
+    if candidate(entry.value, context) != Candidate::Usable {
+        trace!(
+            context,
+            "xelf: {}: stale {} entry {}\n", name, CACHE, entry.value
+        );
+        return None;
+    }
+
+    trace!(
+        context,
+        "xelf: {}: found at {} through {}\n",
+        name,
+        entry.value,
+        Source::Cache.as_str()
+    );
+    PathBuffer::from(entry.value)
+}
+
+/// Path of the library `requester` needs as `name`, searched in the order glibc uses:
+///
+/// 1. `name` itself when it holds a slash;
+/// 2. `DT_RPATH` of the requester, then of the object that loaded it and so on up to
+///    the program, unless the requester has a `DT_RUNPATH`, in which case all are
+///    ignored, as is the `DT_RPATH` of an object that has a `DT_RUNPATH` of its own;
+/// 3. `LD_LIBRARY_PATH`, unless the process is secure;
+/// 4. `DT_RUNPATH` of the requester;
+/// 5. `/etc/ld.so.cache`, then the default directories, unless the requester was
+///    linked with `-z nodeflib`.
+///
+/// Files of another class or machine than the program's are passed over.
+pub fn find(name: &str, requester: usize, map: &LinkMap, context: &Context) -> Option<PathBuffer> {
+    let (Some(object), Some(program)) = (map.get(requester), map.get(0)) else {
+        return None;
+    };
+
+    if name.contains('/') {
+        trace!(context, "xelf: {}: holds a slash, used as is\n", name);
+        return PathBuffer::from(name);
+    }
+
+    let runpath = object.tables.dynamic.runpath().ok().flatten();
+
+    if runpath.is_none() {
+        let mut loader = Some(requester);
+
+        while let Some(object) = loader.and_then(|index| map.get(index)) {
+            loader = object.loader;
+
+            if object.tables.dynamic.runpath().ok().flatten().is_some() {
+                continue;
+            }
+
+            if let Some(rpath) = object.tables.dynamic.rpath().ok().flatten() {
+                let found = search(name, rpath, object.path.directory(), Source::RPath, context);
+                if found.is_some() {
+                    return found;
+                }
+            }
+        }
+    }
+
+    let lists = [
+        (
+            context.library_path,
+            program.path.directory(),
+            Source::LibraryPath,
+        ),
+        (runpath, object.path.directory(), Source::RunPath),
+    ];
+    for (list, origin, source) in lists {
+        if let Some(found) = list.and_then(|list| search(name, list, origin, source, context)) {
+            return Some(found);
+        }
+    }
+
+    // Objects linked with `-z nodeflib` stop short of the system directories
+    if object.tables.dynamic.flags_1().contains(Flag1::NoDefLib) {
+        trace!(
+            context,
+            "xelf: {}: {} skips system directories\n", name, object.name
+        );
+        warn!("xelf: {}: not found\n", name);
+        return None;
+    }
+
+    if let Some(found) = context
+        .cache
+        .and_then(|cache| lookup(name, &cache, context))
+    {
+        return Some(found);
+    }
+
+    // Each default directory is a list of one, already free of tokens
+    let found = DEFAULT
+        .iter()
+        .find_map(|directory| search(name, directory, "", Source::Default, context));
+    if found.is_some() {
+        return found;
+    }
+
+    warn!("xelf: {}: not found\n", name);
+    None
+}
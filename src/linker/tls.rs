@@ -0,0 +1,147 @@
+use arch::memory::Stack;
+use arch::memory::stack::Type;
+use elf::program;
+use syscall::arch_prctl::ARCH_SET_FS;
+use syscall::mmap::{MapFlags, Prot, mmap};
+
+use super::map::LinkMap;
+use crate::result::Result;
+
+/// Alignment of the thread pointer, which glibc's thread descriptor needs as well
+pub const ALIGNMENT: usize = 64;
+
+/// Bytes at the thread pointer when no C library asks for more: the words of glibc's
+/// `tcbhead_t` that compilers and the ABI reach through `%fs`
+pub const HEADER_SIZE: usize = 0x40;
+
+/// Offsets in the thread control block of the words xelf fills
+const SELF: usize = 0x00; /* The thread pointer itself, read by `mov %fs:0` */
+const DTV: usize = 0x08; /* The dynamic thread vector */
+const HEADER_SELF: usize = 0x10; /* The thread pointer again, as glibc's `header.self` */
+const STACK_GUARD: usize = 0x28; /* Canary of `-fstack-protector` code */
+const POINTER_GUARD: usize = 0x30; /* Key of glibc's `PTR_MANGLE` */
+
+/// Bytes of a dynamic thread vector entry, a pointer and the address to free it at
+const DTV_ENTRY: usize = 16;
+
+/// Vector entry of a module without a block
+const UNALLOCATED: usize = usize::MAX;
+
+/// Where the initial thread keeps the TLS block of an object: below the thread pointer,
+/// as in the variant II layout x86-64 uses
+#[derive(Debug, Copy, Clone)]
+pub struct Block {
+    pub module: usize, /* Module id, the object's place in the link map plus one */
+    pub offset: usize, /* Thread pointer minus the address of the block */
+    pub segment: program::Header, /* Its `PT_TLS` template */
+}
+
+/// The static TLS area of the initial thread
+#[derive(Debug, Copy, Clone)]
+pub struct Layout {
+    pub size: usize,  /* Bytes of blocks below the thread pointer */
+    pub align: usize, /* Largest alignment a block or the thread pointer needs */
+}
+
+impl Layout {
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
+/// Give each object with a `PT_TLS` segment its block, in link map order, as glibc lays
+/// out the initial thread's static TLS: each block sits below the previous ones at the
+/// first offset its alignment allows for the address of its template
+pub fn layout(map: &mut LinkMap) -> Result<Layout> {
+    let mut layout = Layout {
+        size: 0,
+        align: ALIGNMENT,
+    };
+
+    for (position, object) in map.iter_mut().enumerate() {
+        let Some(segment) = object.elf.program_headers()?.find(program::Type::Tls) else {
+            continue;
+        };
+
+        let align = (segment.align as usize).max(1);
+        let first = (segment.vaddr as usize).wrapping_neg() & (align - 1);
+        let end = (layout.size + segment.memsz as usize).saturating_sub(first);
+        let offset = end.next_multiple_of(align) + first;
+
+        object.tls = Some(Block {
+            module: position + 1,
+            offset,
+            segment,
+        });
+        layout.size = offset;
+        layout.align = layout.align.max(align);
+    }
+
+    layout.size = layout.size.next_multiple_of(layout.align);
+    Ok(layout)
+}
+
+/// Map the initial thread's static TLS area, `descriptor` bytes of thread control block
+/// and its dynamic thread vector, then make it the thread pointer's target.
+///
+/// The blocks stay zeroed until `initialize` copies their templates in, once relocated.
+/// The stack and pointer guards come from `AT_RANDOM`, as glibc takes them.
+pub fn install(map: &LinkMap, layout: &Layout, descriptor: usize, stack: &Stack) -> Result<usize> {
+    let descriptor = descriptor.max(HEADER_SIZE).next_multiple_of(DTV_ENTRY);
+    let vector = (map.len() + 2) * DTV_ENTRY;
+
+    let base = mmap(
+        0,
+        layout.size + descriptor + vector,
+        Prot::Read | Prot::Write,
+        MapFlags::Private | MapFlags::Anonymous,
+        -1,
+        0,
+    )?
+    .to();
+    let pointer = base + layout.size;
+    let dtv = pointer + descriptor;
+
+    let write = |address: usize, value: usize| unsafe { (address as *mut usize).write(value) };
+
+    // The vector's length comes first, then the generation, then one entry per module
+    write(dtv, map.len());
+    for (position, object) in map.iter().enumerate() {
+        let block = object
+            .tls
+            .map_or(UNALLOCATED, |block| pointer - block.offset);
+        write(dtv + (position + 2) * DTV_ENTRY, block);
+    }
+
+    write(pointer + SELF, pointer);
+    write(pointer + DTV, dtv + DTV_ENTRY);
+    write(pointer + HEADER_SELF, pointer);
+
+    if let Some(random) = unsafe { stack.get_auxv_by_type(Type::Random.to()) } {
+        let random = random as *const usize;
+        let (canary, key) = unsafe { (random.read_unaligned(), random.add(1).read_unaligned()) };
+
+        // A zero low byte stops string functions from leaking the canary
+        write(pointer + STACK_GUARD, canary & !0xff);
+        write(pointer + POINTER_GUARD, key);
+    }
+
+    syscall::arch_prctl(ARCH_SET_FS, pointer)?;
+
+    Ok(pointer)
+}
+
+/// Copy the relocated TLS templates into the blocks of the thread at `pointer`
+pub fn initialize(map: &LinkMap, pointer: usize) {
+    for object in map.iter() {
+        let Some(block) = object.tls else {
+            continue;
+        };
+
+        let template = object.address(block.segment.vaddr) as *const u8;
+        let destination = (pointer - block.offset) as *mut u8;
+        unsafe {
+            core::ptr::copy_nonoverlapping(template, destination, block.segment.filesz as usize)
+        };
+    }
+}
@@ -0,0 +1,293 @@
+pub mod placement;
+pub mod segment;
+pub mod stack;
+
+use core::ffi::CStr;
+
+use arch::memory::Stack;
+use arch::memory::page;
+use elf::header::{Class, Data, Machine};
+use elf::program;
+use syscall::File;
+use syscall::file::Mapping;
+use syscall::mmap::{self, MapFlags, Prot};
+use syscall::open::flags::Flag;
+
+pub use placement::Placement;
+
+use crate::inspect;
+use crate::result::Result;
+
+elf::define_error!(
+    Error,
+    NotExecutable,
+    [
+        [NotExecutable, -1, "Object is not an executable"],
+        [
+            UnsupportedTarget,
+            -2,
+            "Object is not a little-endian 64-bit x86_64 object"
+        ],
+        [
+            InvalidInterpreterPath,
+            -3,
+            "Interpreter path is not a terminated UTF-8 string"
+        ],
+        [
+            NestedInterpreter,
+            -4,
+            "Interpreter asks for an interpreter itself"
+        ],
+        [NoLoadableSegment, -5, "Object has no loadable segment"],
+        [Collision, -6, "Segments overlap memory already in use"],
+        [
+            UnmappedProgramHeaders,
+            -7,
+            "Program headers are not part of a loadable segment"
+        ],
+        [
+            NotShared,
+            -8,
+            "Object is not a position-independent shared object"
+        ],
+    ]
+);
+
+unsafe extern "C" {
+    static __ehdr_start: u8;
+    static _bss_end: u8;
+}
+
+/// An object mapped into memory, described the way its auxiliary vector needs
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Image {
+    pub bias: usize,  /* Mapped minus linked addresses */
+    pub entry: usize, /* Mapped entry point */
+    pub phdr: usize,  /* Mapped program header table */
+    pub phent: usize, /* Size of program header entry */
+    pub phnum: usize, /* Number of program header entries */
+    pub start: usize, /* First mapped page */
+    pub end: usize,   /* End of the last mapped page */
+}
+
+/// A program ready to run, along with the interpreter it asked for
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Program {
+    pub image: Image,               /* The program itself */
+    pub interpreter: Option<Image>, /* Its `PT_INTERP`, if any */
+}
+
+impl Program {
+    /// Where control goes first: the interpreter, which then starts the program
+    pub fn entry(&self) -> usize {
+        self.interpreter.unwrap_or(self.image).entry
+    }
+
+    /// `AT_BASE`: the bias of the interpreter, zero without one
+    pub fn base(&self) -> usize {
+        self.interpreter.map_or(0, |interpreter| interpreter.bias)
+    }
+}
+
+/// Pages occupied by xelf itself, which a program may not be loaded over
+pub fn own_span() -> (usize, usize) {
+    let start = &raw const __ehdr_start as u64;
+    let end = &raw const _bss_end as u64;
+
+    (
+        page::truncate_to_page(start) as usize,
+        page::ceil(end) as usize,
+    )
+}
+
+/// Page-rounded span covered by the loadable segments, at their linked addresses
+pub fn span(headers: &program::Table) -> Result<(usize, usize)> {
+    let mut loads = headers.loads().peekable();
+    if loads.peek().is_none() {
+        return Err(Error::NoLoadableSegment.into());
+    }
+
+    let (start, end) = loads.fold((u64::MAX, 0), |(start, end), header| {
+        (
+            start.min(header.vaddr),
+            end.max(header.vaddr + header.memsz),
+        )
+    });
+
+    Ok((
+        page::truncate_to_page(start) as usize,
+        page::ceil(end) as usize,
+    ))
+}
+
+/// Check the object is one this machine can run: an executable, fixed or
+/// position-independent, or a shared object when one is asked for
+fn check(object: &elf::Elf, shared: bool) -> Result<()> {
+    let header = &object.header;
+
+    if header.ident.class != Class::Elf64
+        || header.ident.data != Data::Lsb
+        || header.machine != Machine::X86_64
+    {
+        return Err(Error::UnsupportedTarget.into());
+    }
+
+    if shared && !header.is_dynamic() {
+        return Err(Error::NotShared.into());
+    }
+
+    if !header.is_executable() && !header.is_dynamic() {
+        return Err(Error::NotExecutable.into());
+    }
+
+    Ok(())
+}
+
+/// Path in the `PT_INTERP` segment, if the object has one
+fn interpreter_path<'a>(
+    object: &elf::Elf<'a>,
+    headers: &program::Table,
+) -> Result<Option<&'a str>> {
+    let Some(header) = headers.find(program::Type::Interp) else {
+        return Ok(None);
+    };
+
+    let path = CStr::from_bytes_until_nul(header.data(&object.reader)?)
+        .ok()
+        .and_then(|path| path.to_str().ok())
+        .ok_or(Error::InvalidInterpreterPath)?;
+
+    Ok(Some(path))
+}
+
+/// Mapped address of the program header table, from `PT_PHDR` or the segment holding it
+fn program_headers(object: &elf::Elf, headers: &program::Table, bias: usize) -> Result<usize> {
+    if let Some(header) = headers.find(program::Type::PHdr) {
+        return Ok(bias + header.vaddr as usize);
+    }
+
+    let phoff = object.header.phoff;
+    headers
+        .loads()
+        .find(|header| header.offset <= phoff && phoff < header.offset + header.filesz)
+        .map(|header| bias + (header.vaddr + phoff - header.offset) as usize)
+        .ok_or(Error::UnmappedProgramHeaders.into())
+}
+
+/// Reserve `length` bytes for segments linked from `start` and return where they begin.
+///
+/// A fixed executable gets its linked `start` or nothing, so a range the kernel already
+/// handed out is refused rather than silently replaced by the fixed mappings that follow.
+fn reserve(start: usize, length: usize, placement: Placement) -> Result<usize> {
+    let flags = MapFlags::Private | MapFlags::Anonymous;
+    let address = match placement {
+        Placement::Linked => start,
+        Placement::Preferred(base) => base,
+        Placement::Anywhere => {
+            return Ok(mmap::mmap(0, length, Prot::None.into(), flags, -1, 0)?.to());
+        }
+    };
+
+    match mmap::mmap(
+        address,
+        length,
+        Prot::None.into(),
+        flags | MapFlags::FixedNoReplace,
+        -1,
+        0,
+    ) {
+        Ok(address) => Ok(address.to()),
+        Err(syscall::Error::Mmap(mmap::Error::FileExists)) if placement != Placement::Linked => {
+            reserve(start, length, Placement::Anywhere)
+        }
+        Err(syscall::Error::Mmap(mmap::Error::FileExists)) => Err(Error::Collision.into()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Map the segments of an object opened as `file` where `placement` says
+fn map(
+    object: &elf::Elf,
+    headers: &program::Table,
+    file: &File,
+    placement: Placement,
+) -> Result<Image> {
+    let (start, end) = span(headers)?;
+
+    let (own_start, own_end) = own_span();
+    if placement == Placement::Linked && start < own_end && own_start < end {
+        return Err(Error::Collision.into());
+    }
+
+    let base = reserve(start, end - start, placement)?;
+    let bias = base - start;
+    for header in headers.loads() {
+        segment::map(file, &header, bias)?;
+    }
+
+    Ok(Image {
+        bias,
+        entry: bias + object.header.entry as usize,
+        phdr: program_headers(object, headers, bias)?,
+        phent: object.header.phentsize as usize,
+        phnum: headers.len(),
+        start: base,
+        end: base + (end - start),
+    })
+}
+
+/// Open `path` and map the whole file read-only to parse it
+fn open(path: &str) -> Result<(File, Mapping)> {
+    let file = File::open(path, Flag::RDONLY as usize | Flag::CLOEXEC as usize)?;
+    let mapping = file.map(Prot::Read.into())?;
+
+    Ok((file, mapping))
+}
+
+/// Map the shared object at `path` where `placement` says
+pub fn load_shared(path: &str, placement: Placement) -> Result<Image> {
+    let (file, mapping) = open(path)?;
+    let object = elf::Elf::parse(mapping.as_slice())?;
+    let headers = object.program_headers()?;
+
+    check(&object, true)?;
+    map(&object, &headers, &file, placement)
+}
+
+/// Map the interpreter at `path`, which must not ask for an interpreter itself
+fn load_interpreter(path: &str, stack: &Stack) -> Result<Image> {
+    let (file, mapping) = open(path)?;
+    let object = elf::Elf::parse(mapping.as_slice())?;
+    let headers = object.program_headers()?;
+
+    check(&object, true)?;
+    if interpreter_path(&object, &headers)?.is_some() {
+        return Err(Error::NestedInterpreter.into());
+    }
+
+    map(&object, &headers, &file, placement::interpreter(stack))
+}
+
+/// Map the executable at `path`, then the interpreter it names in `PT_INTERP` if any.
+///
+/// A fixed executable goes at its linked addresses, a position-independent one and its
+/// interpreter at bases chosen from `stack`.
+pub fn load(path: &str, stack: &Stack) -> Result<Program> {
+    let (file, mapping) = open(path)?;
+    let object = elf::Elf::parse(mapping.as_slice())?;
+    let headers = object.program_headers()?;
+
+    check(&object, false)?;
+    inspect::report(
+        &object,
+        unsafe { stack.get_env_by_name(crate::DEBUG) }.is_some(),
+    );
+
+    let image = map(&object, &headers, &file, placement::program(stack, &object))?;
+    let interpreter = match interpreter_path(&object, &headers)? {
+        Some(path) => Some(load_interpreter(path, stack)?),
+        None => None,
+    };
+
+    Ok(Program { image, interpreter })
+}
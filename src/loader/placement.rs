@@ -0,0 +1,64 @@
+use arch::memory::Stack;
+use arch::memory::page;
+use arch::memory::stack::Type;
+use arch::memory::stack::builder::RANDOM_SIZE;
+use syscall::getrandom::{GRND_NONBLOCK, getrandom};
+
+/// Environment variable asking for position-independent programs at a fixed base
+pub const DETERMINISTIC: &str = "XELF_DETERMINISTIC";
+
+/// Whether the environment asks for the same layout on every run
+pub fn is_deterministic(stack: &Stack) -> bool {
+    matches!(unsafe { stack.get_env_by_name(DETERMINISTIC) }, Some(value) if !value.is_empty() && value != "0")
+}
+
+/// Fill `bytes` from `getrandom`, or from the `AT_RANDOM` bytes when the kernel has none
+/// to give
+pub fn random(stack: &Stack, bytes: &mut [u8]) {
+    if getrandom(bytes.as_mut_ptr(), bytes.len(), GRND_NONBLOCK) == Ok(bytes.len() as isize) {
+        return;
+    }
+
+    // The program seeds its stack protector from their start, so they are taken from the end
+    if let Some(address) = unsafe { stack.get_auxv_by_type(Type::Random.to()) } {
+        let kernel = unsafe { &*(address as *const [u8; RANDOM_SIZE]) };
+        let count = bytes.len().min(RANDOM_SIZE);
+        bytes[..count].copy_from_slice(&kernel[RANDOM_SIZE - count..]);
+    }
+}
+
+/// Where an object's segments go
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Placement {
+    Linked,           /* At their linked addresses, refused when taken */
+    Preferred(usize), /* At this base when free, else where the kernel picks */
+    Anywhere,         /* Where the kernel picks */
+}
+
+/// Base for a position-independent program: randomised, unless a deterministic layout is asked for
+pub fn base(stack: &Stack) -> usize {
+    let mut random = [0; 8];
+    if !is_deterministic(stack) {
+        self::random(stack, &mut random);
+    }
+    let random = u64::from_ne_bytes(random);
+
+    page::dynamic_base(random) as usize
+}
+
+/// Placement of the program itself
+pub fn program(stack: &Stack, object: &elf::Elf) -> Placement {
+    match object.header.is_dynamic() {
+        true => Placement::Preferred(base(stack)),
+        false => Placement::Linked,
+    }
+}
+
+/// Placement of the interpreter: where the kernel's randomised `mmap` puts it, as the
+/// kernel itself does, or at a fixed base for a deterministic layout
+pub fn interpreter(stack: &Stack) -> Placement {
+    match is_deterministic(stack) {
+        true => Placement::Preferred(page::INTERPRETER_BASE as usize),
+        false => Placement::Anywhere,
+    }
+}
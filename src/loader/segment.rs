@@ -0,0 +1,85 @@
+use arch::memory::page;
+use elf::program::Header;
+use syscall::File;
+use syscall::mmap::{Address, MapFlags, Prot, mmap};
+use syscall::mprotect::mprotect;
+
+use crate::result::Result;
+
+/// `Prot` bits granting what the segment flags ask for
+pub fn protection(header: &Header) -> usize {
+    let mut protection: usize = Prot::None.into();
+
+    if header.flags.is_readable() {
+        protection = protection | Prot::Read;
+    }
+    if header.flags.is_writable() {
+        protection = protection | Prot::Write;
+    }
+    if header.flags.is_executable() {
+        protection = protection | Prot::Exec;
+    }
+
+    protection
+}
+
+/// Map one `PT_LOAD` segment `bias` bytes past its linked address.
+///
+/// The file-backed pages are mapped over the reservation, the tail of the last one
+/// is cleared and the rest of the `.bss` is covered with anonymous pages.
+pub fn map(file: &File, header: &Header, bias: usize) -> Result<()> {
+    let protection = protection(header);
+    let start = bias + header.vaddr as usize;
+    let page = page::truncate_to_page(start as u64) as usize;
+    let file_end = start + header.filesz as usize;
+    let memory_end = start + header.memsz as usize;
+
+    if header.filesz > 0 {
+        mmap(
+            page,
+            file_end - page,
+            protection,
+            MapFlags::Private | MapFlags::Fixed,
+            file.fd.as_raw(),
+            header.offset as usize - (start - page),
+        )?;
+    }
+
+    if header.memsz <= header.filesz {
+        return Ok(());
+    }
+
+    let mut anonymous = page;
+    if header.filesz > 0 {
+        anonymous = page::ceil(file_end as u64) as usize;
+
+        // The last file page carries whatever follows the segment in the file
+        let tail = anonymous - file_end;
+        if tail > 0 {
+            let last = anonymous - page::SIZE as usize;
+            let writable = header.flags.is_writable();
+
+            if !writable {
+                mprotect(Address(last), page::SIZE as usize, protection | Prot::Write)?;
+            }
+            unsafe { core::ptr::write_bytes(file_end as *mut u8, 0, tail) };
+            if !writable {
+                mprotect(Address(last), page::SIZE as usize, protection)?;
+            }
+        }
+    }
+
+    let end = page::ceil(memory_end as u64) as usize;
+    if end > anonymous {
+        mmap(
+            anonymous,
+            end - anonymous,
+            protection,
+            MapFlags::Private | MapFlags::Anonymous | MapFlags::Fixed,
+            -1,
+            0,
+        )?;
+    }
+
+    Ok(())
+}
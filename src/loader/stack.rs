@@ -0,0 +1,60 @@
+use arch::memory::Stack;
+use arch::memory::stack::builder::RANDOM_SIZE;
+use arch::memory::stack::{AuxEntry, Builder, Type};
+use syscall::mmap::{MapFlags, Prot, mmap};
+
+use super::Program;
+use super::placement;
+use crate::result::Result;
+
+/// Size of the stack mapped for the loaded program
+pub const SIZE: usize = 8 << 20;
+
+/// Auxiliary vector entry the program sees in place of xelf's `entry`
+fn entry(entry: AuxEntry, program: &Program) -> AuxEntry {
+    let value = match Type::from(entry.atype) {
+        Type::PHdr => program.image.phdr,
+        Type::PHEnt => program.image.phent,
+        Type::PHNum => program.image.phnum,
+        Type::Entry => program.image.entry,
+        Type::Base => program.base(),
+        _ => entry.value,
+    };
+
+    AuxEntry {
+        atype: entry.atype,
+        value,
+    }
+}
+
+/// Build the initial stack of `program` on a fresh mapping and return its stack pointer.
+///
+/// The program sees xelf's own arguments without the first one, the same environment
+/// and an auxiliary vector describing the program instead of xelf.
+pub fn build(stack: &Stack, program: &Program) -> Result<usize> {
+    // Fresh bytes for `AT_RANDOM`, or the kernel's own when `getrandom` has none to give
+    let mut random = [0; RANDOM_SIZE];
+    placement::random(stack, &mut random);
+
+    let builder = Builder::new(
+        stack.arguments().skip(1),
+        stack.environment(),
+        stack.auxiliary().map(|aux| entry(aux, program)),
+        random,
+    );
+
+    let base = mmap(
+        0,
+        SIZE,
+        Prot::Read | Prot::Write,
+        MapFlags::Private | MapFlags::Anonymous | MapFlags::Stack,
+        -1,
+        0,
+    )?;
+    let region = unsafe { core::slice::from_raw_parts_mut(base.as_ptr::<u8>(), SIZE) };
+
+    match builder.write(region) {
+        Some(pointer) => Ok(pointer as usize),
+        None => Err(syscall::Error::Mmap(syscall::mmap::Error::OutOfMemory).into()),
+    }
+}
@@ -1,51 +1,40 @@
 #![no_std]
 #![no_main]
 
-use xelf;
+use result::ErrorTrait;
 
+/// Called by `_start` with the stack the kernel handed to xelf.
+///
+/// # Safety
+///
+/// `stack_pointer` must point at the argument count of the initial process stack.
 #[unsafe(no_mangle)]
-pub extern "C" fn entry(stack_pointer: *mut u64) -> ! {
-    xelf::info!("eXecuting Executable and Linkable Format\n");
-
-    // Create a Stack instance from the provided pointer
+pub unsafe extern "C" fn entry(stack_pointer: *mut u64) -> ! {
     let mut stack = unsafe { arch::memory::Stack::from_pointer(stack_pointer) };
-    xelf::info!("Stack from pointer:\n");
-    stack.print();
-
-    // Access specific stack elements
-    unsafe {
-        if let Some(arg0) = stack.get_arg(0) {
-            xelf::info!("Program name: {}\n", arg0);
-        }
 
-        // Check for specific arguments
-        for i in 0..stack.argc {
-            if let Some(arg) = stack.get_arg(i) {
-                if arg == "/" {
-                    xelf::info!("Found '/' at argument position {}\n", i);
-                }
+    if xelf::linker::is_interpreter(&stack) {
+        match xelf::linker::run(&mut stack) {
+            Ok(entry) => unsafe { arch::transfer(entry, stack_pointer as usize) },
+            Err(error) => {
+                xelf::warn!("xelf2: {}\n", error.describe());
+                syscall::exit(127);
             }
         }
+    }
 
-        // Get environment variables
-        if let Some(path) = stack.get_env_by_name("PATH") {
-            xelf::info!("PATH environment variable: {}\n", path);
-        }
+    let Some(path) = (unsafe { stack.get_arg(1) }) else {
+        xelf::warn!("Usage: xelf2 <program> [arguments...]\n");
+        syscall::exit(1);
+    };
 
-        // Get and modify auxiliary vector entries
-        if let Some(entry_point) = stack.get_auxv_by_type(9) {
-            // AT_ENTRY
-            xelf::info!("Original entry point: {:#x}\n", entry_point);
+    let program = xelf::loader::load(path, &stack)
+        .and_then(|program| Ok((program, xelf::loader::stack::build(&stack, &program)?)));
 
-            // Modify an auxiliary vector entry (example only, not actually changing it)
-            if stack.set_auxv_by_type(9, 0x11502) {
-                xelf::info!("Entry point updated (demonstrative only)\n");
-            }
+    match program {
+        Ok((program, pointer)) => unsafe { arch::transfer(program.entry(), pointer) },
+        Err(error) => {
+            xelf::warn!("xelf2: {}: {}\n", path, error.describe());
+            syscall::exit(127);
         }
     }
-
-    xelf::info!("\nDemonstration complete\n");
-
-    stack.print();
-    panic!("Stack demonstration completed successfully!");
 }
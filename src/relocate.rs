@@ -0,0 +1,49 @@
+use elf::dynamic::tag::{DT_NULL, DT_RELA, DT_RELAENT, DT_RELASZ};
+use elf::relocation::x86_64::{R_X86_64_NONE, R_X86_64_RELATIVE};
+
+/// Size of an `Elf64_Rela` entry, unless `DT_RELAENT` says otherwise
+const RELA_SIZE: u64 = 24;
+
+/// Apply the `R_X86_64_RELATIVE` relocations of the image loaded at `base`, as listed by
+/// its `_DYNAMIC` array.
+///
+/// Called by `_start` before anything touches a global, so nothing here may read a
+/// pointer stored in the image or panic: both would go through data not yet relocated.
+/// A static-PIE has no other relocation type, anything else stops the process.
+///
+/// # Safety
+///
+/// `base` must be where the image was loaded and `dynamic` its own `_DYNAMIC`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn relocate(base: u64, dynamic: *const u64) {
+    let (mut table, mut size, mut entry) = (0, 0, RELA_SIZE);
+
+    let mut cursor = dynamic;
+    loop {
+        let (tag, value) = unsafe { (*cursor as i64, *cursor.add(1)) };
+        match tag {
+            DT_NULL => break,
+            DT_RELA => table = value,
+            DT_RELASZ => size = value,
+            DT_RELAENT => entry = value,
+            _ => {}
+        }
+        cursor = unsafe { cursor.add(2) };
+    }
+
+    let mut offset = 0;
+    while offset < size {
+        let rela = base.wrapping_add(table).wrapping_add(offset) as *const u64;
+        let (address, info, addend) = unsafe { (*rela, *rela.add(1), *rela.add(2)) };
+
+        match info as u32 {
+            R_X86_64_RELATIVE => unsafe {
+                *(base.wrapping_add(address) as *mut u64) = base.wrapping_add(addend)
+            },
+            R_X86_64_NONE => {}
+            _ => unsafe { core::arch::asm!("ud2", options(noreturn)) },
+        }
+
+        offset = offset.wrapping_add(entry);
+    }
+}
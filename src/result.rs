@@ -1,37 +1,71 @@
+use elf::result::Error as ElfError;
 use human::result::Error as HumanError;
 use syscall::result::Error as SyscallError;
 
+use crate::linker::Error as LinkerError;
+use crate::loader::Error as LoaderError;
+
 #[repr(isize)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Error {
     Syscall(SyscallError),
     Human(HumanError),
+    Elf(ElfError),
+    Loader(LoaderError),
+    Linker(LinkerError),
     TODO,
 }
 
 impl result::ErrorTrait for Error {
-    fn from_no(errno: isize) -> Error {
-        match -errno {
-            _ => Error::TODO,
-        }
+    fn from_no(_errno: isize) -> Error {
+        Error::TODO
     }
 
     fn describe(&self) -> &str {
         match self {
+            Error::Syscall(error) => error.describe(),
+            Error::Elf(error) => error.describe(),
+            Error::Loader(error) => error.describe(),
+            Error::Linker(error) => error.describe(),
             _ => "TODO",
         }
     }
 
     fn advert(&self) -> Option<isize> {
-        match self {
-            _ => None,
-        }
+        None
     }
 }
 
-impl Into<isize> for Error {
-    fn into(self) -> isize {
-        match self {
+impl From<SyscallError> for Error {
+    fn from(error: SyscallError) -> Self {
+        Error::Syscall(error)
+    }
+}
+
+impl From<ElfError> for Error {
+    fn from(error: ElfError) -> Self {
+        Error::Elf(error)
+    }
+}
+
+impl From<LoaderError> for Error {
+    fn from(error: LoaderError) -> Self {
+        Error::Loader(error)
+    }
+}
+
+impl From<LinkerError> for Error {
+    fn from(error: LinkerError) -> Self {
+        Error::Linker(error)
+    }
+}
+
+impl From<Error> for isize {
+    fn from(error: Error) -> isize {
+        match error {
+            Error::Linker(_e) => -7,
+            Error::Loader(_e) => -6,
+            Error::Elf(_e) => -5,
             Error::Human(_e) => -4,
             Error::Syscall(_e) => -2,
             Error::TODO => -1,
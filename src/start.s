@@ -5,21 +5,27 @@
    .section .text._start
    .type   _start,@function
    _start:
-       # Store the original stack pointer
-       mov     %rsp, %rdi
+       # Keep the original stack pointer across the calls below
+       mov     %rsp, %rbx
 
        # Always ensure 16-byte alignment
        and     $-16, %rsp
 
-       # Create a standard stack frame
+       # Create a standard stack frame, leaving the stack aligned for calls
        push    %rbp
        mov     %rsp, %rbp
-       sub     $16, %rsp        # Reserve some stack space
+       sub     $8, %rsp
+
+       # Apply our own relocations before anything touches a global
+       # xelf is linked at zero, so the ELF header marks where we were loaded
+       lea     __ehdr_start(%rip), %rdi
+       lea     _DYNAMIC(%rip), %rsi
+       call    relocate
 
        # Initialize BSS section to zero
        # bss_start and bss_end are provided by the linker script
-       mov     $_bss_start, %rax
-       mov     $_bss_end, %rcx
+       lea     _bss_start(%rip), %rax
+       lea     _bss_end(%rip), %rcx
        cmp     %rcx, %rax
        je      bss_init_done
 bss_zero_loop:
@@ -28,12 +34,9 @@ bss_zero_loop:
        cmp     %rcx, %rax
        jl      bss_zero_loop
 bss_init_done:
-       
-       # Initialize any relocations if needed
-       # This would typically be done by the dynamic loader
-       # For our static binary, we don't need much here
 
        # Call the Rust entry point
+       mov     %rbx, %rdi
        call    entry
 
        # We shouldn't return, but clean up anyway
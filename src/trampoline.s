@@ -0,0 +1,86 @@
+   .text
+   .globl  runtime_resolve
+   .hidden runtime_resolve
+   .section .text.runtime_resolve
+   .align  16
+   .type   runtime_resolve,@function
+   # PLT0 jumps here on the first call through a lazily bound slot, having pushed
+   # GOT[1], the object's place in the link map, over the index of the slot's
+   # relocation and the caller's return address
+   runtime_resolve:
+       # Keep a frame pointer to the pushed words across the realignment below
+       push    %rbx
+       mov     %rsp, %rbx
+
+       # Argument registers, with %rax for variadic calls and %r10 for static chains
+       sub     $64, %rsp
+       mov     %rax, 0(%rsp)
+       mov     %rcx, 8(%rsp)
+       mov     %rdx, 16(%rsp)
+       mov     %rsi, 24(%rsp)
+       mov     %rdi, 32(%rsp)
+       mov     %r8, 40(%rsp)
+       mov     %r9, 48(%rsp)
+       mov     %r10, 56(%rsp)
+
+       # Vector registers, with XSAVE when the kernel enabled it
+       mov     XELF_STATE_SIZE(%rip), %rax
+       test    %rax, %rax
+       jz      .Lfxsave
+       sub     %rax, %rsp
+       and     $-64, %rsp
+
+       # XSAVE only writes the bits of XSTATE_BV the kernel enabled, and none of the
+       # rest of the header, while XRSTOR faults on any other bit set
+       movq    $0, 512(%rsp)
+       movq    $0, 520(%rsp)
+       movq    $0, 528(%rsp)
+       movq    $0, 536(%rsp)
+       movq    $0, 544(%rsp)
+       movq    $0, 552(%rsp)
+       movq    $0, 560(%rsp)
+       movq    $0, 568(%rsp)
+       mov     $-1, %eax
+       mov     $-1, %edx
+       xsave64 (%rsp)
+       jmp     .Lsaved
+.Lfxsave:
+       sub     $512, %rsp
+       and     $-64, %rsp
+       fxsave64 (%rsp)
+.Lsaved:
+
+       # Bind the slot, keeping the function over the relocation index
+       mov     8(%rbx), %rdi
+       mov     16(%rbx), %rsi
+       call    lazy_bind
+       mov     %rax, 16(%rbx)
+
+       mov     XELF_STATE_SIZE(%rip), %rax
+       test    %rax, %rax
+       jz      .Lfxrstor
+       mov     $-1, %eax
+       mov     $-1, %edx
+       xrstor64 (%rsp)
+       jmp     .Lrestored
+.Lfxrstor:
+       fxrstor64 (%rsp)
+.Lrestored:
+
+       lea     -64(%rbx), %rsp
+       mov     0(%rsp), %rax
+       mov     8(%rsp), %rcx
+       mov     16(%rsp), %rdx
+       mov     24(%rsp), %rsi
+       mov     32(%rsp), %rdi
+       mov     40(%rsp), %r8
+       mov     48(%rsp), %r9
+       mov     56(%rsp), %r10
+
+       # Drop our frame and PLT0's two words, then enter the function as the caller
+       # would have, %r11 being free across calls
+       mov     16(%rbx), %r11
+       mov     %rbx, %rsp
+       pop     %rbx
+       add     $16, %rsp
+       jmp     *%r11